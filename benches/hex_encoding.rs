@@ -0,0 +1,38 @@
+// benches/hex_encoding.rs
+// to_hex (fresh String per call) vs to_hex_into (reused buffer) on large secrets
+// Run with: cargo bench --bench hex_encoding --features conversions
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use secure_gate::{Dynamic, SecureConversionsExt};
+
+const SIZES: [usize; 3] = [32, 1024, 4096];
+
+fn bench_to_hex(c: &mut Criterion) {
+    let mut group = c.benchmark_group("to_hex");
+    for size in SIZES {
+        let secret = Dynamic::<Vec<u8>>::new(vec![0xA5u8; size]);
+        group.bench_with_input(BenchmarkId::from_parameter(size), &secret, |b, secret| {
+            b.iter(|| black_box(secret.expose_secret().to_hex()))
+        });
+    }
+    group.finish();
+}
+
+fn bench_to_hex_into(c: &mut Criterion) {
+    let mut group = c.benchmark_group("to_hex_into");
+    for size in SIZES {
+        let secret = Dynamic::<Vec<u8>>::new(vec![0xA5u8; size]);
+        let mut out = String::with_capacity(size * 2);
+        group.bench_with_input(BenchmarkId::from_parameter(size), &secret, |b, secret| {
+            b.iter(|| {
+                out.clear();
+                secret.expose_secret().to_hex_into(&mut out);
+                black_box(out.len())
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_to_hex, bench_to_hex_into);
+criterion_main!(benches);
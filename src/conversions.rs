@@ -7,17 +7,26 @@
 #[cfg(feature = "conversions")]
 use alloc::string::String;
 #[cfg(feature = "conversions")]
+use alloc::vec::Vec;
+#[cfg(feature = "conversions")]
 use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 #[cfg(feature = "conversions")]
 use base64::Engine;
 #[cfg(feature = "conversions")]
 use zeroize::Zeroize;
 
+#[cfg(feature = "conversions")]
+use crate::SecureGateError;
+
 /// Extension trait for safe, explicit conversions of secret byte data.
 ///
 /// All methods require the caller to first call `.expose_secret()` (or `.expose_secret_mut()`).
 /// This makes every secret access loud, grep-able, and auditable.
 ///
+/// Implementors supply the encoders, `ct_eq`, and [`ct_bytes`](Self::ct_bytes).
+/// The constant-time helpers are provided on top of `ct_bytes()`, so they work on
+/// the secret in place for every implementor — no decoding, copies, or panics.
+///
 /// # Example
 ///
 /// ```
@@ -31,12 +40,31 @@ use zeroize::Zeroize;
 #[cfg(feature = "conversions")]
 pub trait SecureConversionsExt {
     /// Encode secret bytes as lowercase hexadecimal.
+    #[must_use = "dropping a converted secret — did you mean to use it?"]
     fn to_hex(&self) -> String;
 
+    /// Encode secret bytes as lowercase hexadecimal, appending to `out`.
+    ///
+    /// Produces exactly the same characters as [`to_hex`](Self::to_hex), but reuses
+    /// the caller's buffer instead of allocating a fresh `String` per call — a
+    /// noticeable win when encoding large secrets repeatedly (e.g. in logging paths).
+    ///
+    /// The provided body appends [`to_hex`](Self::to_hex) and wipes the temporary
+    /// (with `zeroize`); the crate's own impls encode straight into `out`.
+    #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+    fn to_hex_into(&self, out: &mut String) {
+        let mut hex = self.to_hex();
+        out.push_str(&hex);
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut hex);
+    }
+
     /// Encode secret bytes as uppercase hexadecimal.
+    #[must_use = "dropping a converted secret — did you mean to use it?"]
     fn to_hex_upper(&self) -> String;
 
     /// Encode secret bytes as URL-safe base64 (no padding).
+    #[must_use = "dropping a converted secret — did you mean to use it?"]
     fn to_base64url(&self) -> String;
 
     /// Constant-time equality comparison.
@@ -44,18 +72,68 @@ pub trait SecureConversionsExt {
     /// Returns `true` if the two secrets are equal, `false` otherwise.
     /// Uses `subtle::ConstantTimeEq` under the hood – safe against timing attacks.
     fn ct_eq(&self, other: &Self) -> bool;
+
+    /// The raw secret bytes, borrowed without copying or encoding.
+    ///
+    /// The provided constant-time helpers read from this slice, so an implementation
+    /// should return the secret's own storage rather than a derived buffer.
+    fn ct_bytes(&self) -> &[u8];
+
+    /// XOR of all bytes — a branch-free parity accumulator.
+    ///
+    /// Constant-time in the data values: the loop runs exactly once per byte with
+    /// no data-dependent branches, so timing depends only on the length. Returns `0`
+    /// for empty input.
+    fn ct_fold_xor(&self) -> u8 {
+        fold_xor(self.ct_bytes())
+    }
+
+    /// Constant-time comparison of `other` against `self[offset..offset + other.len()]`.
+    ///
+    /// Returns `false` if that range is out of bounds. Every byte of `self` is visited
+    /// and the bounds check is folded into the result, so timing depends only on the
+    /// lengths — not on the data, the mismatch position, or whether the range fits.
+    /// Useful for verifying a (truncated) tag stored at a computed offset.
+    fn ct_eq_at(&self, offset: usize, other: &[u8]) -> bool {
+        ct_eq_window(self.ct_bytes(), offset, other)
+    }
+
+    /// Constant-time byte membership: `true` if any byte of `self` equals `byte`.
+    ///
+    /// Unlike `contains()`, this never exits early — every byte is compared and the
+    /// results are OR-accumulated, so timing depends only on the length. Useful for
+    /// checking derived key material against a forbidden marker byte.
+    fn ct_contains(&self, byte: u8) -> bool {
+        ct_contains_byte(self.ct_bytes(), byte)
+    }
+
+    /// Constant-time substring search: `true` if any `window.len()`-byte window of
+    /// `self` equals `window`.
+    ///
+    /// Every window is compared in constant time and the results are OR-accumulated
+    /// without early exit, so timing depends only on the two lengths — not on the
+    /// contents or where a match occurs. An empty `window` always matches; one longer
+    /// than `self` never does.
+    fn ct_contains_window(&self, window: &[u8]) -> bool {
+        ct_contains_slice(self.ct_bytes(), window)
+    }
 }
 
 #[cfg(feature = "conversions")]
 impl SecureConversionsExt for [u8] {
     #[inline(always)]
     fn to_hex(&self) -> String {
-        hex::encode(self)
+        encode_hex(self, false)
+    }
+
+    #[inline(always)]
+    fn to_hex_into(&self, out: &mut String) {
+        encode_hex_into(self, out);
     }
 
     #[inline(always)]
     fn to_hex_upper(&self) -> String {
-        hex::encode_upper(self)
+        encode_hex(self, true)
     }
 
     #[inline(always)]
@@ -67,18 +145,28 @@ impl SecureConversionsExt for [u8] {
     fn ct_eq(&self, other: &Self) -> bool {
         subtle::ConstantTimeEq::ct_eq(self, other).into()
     }
+
+    #[inline(always)]
+    fn ct_bytes(&self) -> &[u8] {
+        self
+    }
 }
 
 #[cfg(feature = "conversions")]
 impl<const N: usize> SecureConversionsExt for [u8; N] {
     #[inline(always)]
     fn to_hex(&self) -> String {
-        hex::encode(self)
+        encode_hex(self, false)
+    }
+
+    #[inline(always)]
+    fn to_hex_into(&self, out: &mut String) {
+        encode_hex_into(self, out);
     }
 
     #[inline(always)]
     fn to_hex_upper(&self) -> String {
-        hex::encode_upper(self)
+        encode_hex(self, true)
     }
 
     #[inline(always)]
@@ -90,6 +178,189 @@ impl<const N: usize> SecureConversionsExt for [u8; N] {
     fn ct_eq(&self, other: &Self) -> bool {
         subtle::ConstantTimeEq::ct_eq(self.as_slice(), other.as_slice()).into()
     }
+
+    #[inline(always)]
+    fn ct_bytes(&self) -> &[u8] {
+        self
+    }
+}
+
+// Hex-encode `bytes` into a new `String` — the `hex` crate's table lookup by default,
+// the branchless encoder below with `ct-encoding`
+#[cfg(all(feature = "conversions", not(feature = "ct-encoding")))]
+#[inline]
+fn encode_hex(bytes: &[u8], upper: bool) -> String {
+    if upper {
+        hex::encode_upper(bytes)
+    } else {
+        hex::encode(bytes)
+    }
+}
+
+#[cfg(feature = "ct-encoding")]
+#[inline]
+fn encode_hex(bytes: &[u8], upper: bool) -> String {
+    let mut out = String::with_capacity(bytes.len() * 2);
+    ct_encode_hex_into(bytes, &mut out, if upper { b'A' } else { b'a' });
+    out
+}
+
+// Append lowercase hex for `bytes` to `out` — one up-front reservation, no temporary `String`
+#[cfg(not(feature = "ct-encoding"))]
+#[inline]
+fn encode_hex_into(bytes: &[u8], out: &mut String) {
+    const HEX_LOWER: &[u8; 16] = b"0123456789abcdef";
+
+    out.reserve(bytes.len() * 2);
+    for &b in bytes {
+        out.push(HEX_LOWER[(b >> 4) as usize] as char);
+        out.push(HEX_LOWER[(b & 0x0f) as usize] as char);
+    }
+}
+
+#[cfg(feature = "ct-encoding")]
+#[inline]
+fn encode_hex_into(bytes: &[u8], out: &mut String) {
+    ct_encode_hex_into(bytes, out, b'a');
+}
+
+// Branchless encoder: no table lookups or branches indexed by secret nibbles.
+// `alpha` is `b'a'` or `b'A'` and selects the case of the letter digits.
+#[cfg(feature = "ct-encoding")]
+fn ct_encode_hex_into(bytes: &[u8], out: &mut String, alpha: u8) {
+    out.reserve(bytes.len() * 2);
+    for &b in bytes {
+        out.push(ct_hex_digit(b >> 4, alpha) as char);
+        out.push(ct_hex_digit(b & 0x0f, alpha) as char);
+    }
+}
+
+// Map a nibble to its ASCII hex digit: `gt9` is all ones exactly when `n > 9`,
+// and then shifts the result from the `'0'..` range to the `alpha..` range
+#[cfg(feature = "ct-encoding")]
+#[inline(always)]
+fn ct_hex_digit(n: u8, alpha: u8) -> u8 {
+    let n = n as i16;
+    let gt9 = (9 - n) >> 8;
+    (n + b'0' as i16 + (gt9 & (alpha as i16 - b'0' as i16 - 10))) as u8
+}
+
+// Branchless decoder for already-validated lowercase hex: each output byte is
+// computed arithmetically from its two digits, with no table lookups
+#[cfg(feature = "conversions")]
+fn ct_decode_hex(hex: &[u8]) -> Vec<u8> {
+    hex.chunks_exact(2)
+        .map(|pair| (ct_hex_value(pair[0]) << 4) | ct_hex_value(pair[1]))
+        .collect()
+}
+
+// Map a lowercase ASCII hex digit to its nibble: `gt9` is all ones exactly when
+// `c > '9'`, and then removes the gap between `'9'` and `'a'`
+#[cfg(feature = "conversions")]
+#[inline(always)]
+fn ct_hex_value(c: u8) -> u8 {
+    let c = c as i16;
+    let gt9 = (b'9' as i16 - c) >> 8;
+    (c - b'0' as i16 - (gt9 & (b'a' as i16 - b'0' as i16 - 10))) as u8
+}
+
+// Lowercase ASCII hex digits in place; returns `false` if any byte is not a hex digit.
+// Only `A-F` are ever modified, so a `String` buffer stays valid UTF-8 either way.
+#[cfg(feature = "conversions")]
+#[inline]
+fn normalize_hex(bytes: &mut [u8]) -> bool {
+    let mut valid = true;
+    for b in bytes.iter_mut() {
+        let (lower, ok) = normalize_hex_byte(*b);
+        *b = lower;
+        valid &= ok;
+    }
+    valid
+}
+
+// Lowercase one hex digit, reporting whether it was one
+#[cfg(all(feature = "conversions", not(feature = "ct-encoding")))]
+#[inline(always)]
+fn normalize_hex_byte(c: u8) -> (u8, bool) {
+    match c {
+        b'A'..=b'F' => (c + 32, true), // 'A' → 'a'
+        b'a'..=b'f' | b'0'..=b'9' => (c, true),
+        _ => (c, false),
+    }
+}
+
+#[cfg(feature = "ct-encoding")]
+#[inline(always)]
+fn normalize_hex_byte(c: u8) -> (u8, bool) {
+    let upper = ct_in_range(c, b'A', b'F');
+    let valid = ct_in_range(c, b'0', b'9') | ct_in_range(c, b'a', b'f') | upper;
+    (c | (upper & 0x20), valid == 0xFF)
+}
+
+// `0xFF` if `lo <= c <= hi`, else `0x00` — both differences are negative only inside
+// the range, so the sign bit of their AND is the answer
+#[cfg(feature = "ct-encoding")]
+#[inline(always)]
+fn ct_in_range(c: u8, lo: u8, hi: u8) -> u8 {
+    let c = c as i16;
+    (((lo as i16 - 1 - c) & (c - hi as i16 - 1)) >> 8) as u8
+}
+
+// XOR-reduce `bytes` – fixed iteration over the length, no data-dependent branches
+#[cfg(feature = "conversions")]
+#[inline]
+fn fold_xor(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0u8, |acc, &b| acc ^ b)
+}
+
+// Compare `other` with the window of `bytes` starting at `offset`, scanning all of
+// `bytes` and masking positions outside the window, so neither the data nor the
+// bounds outcome affects control flow
+#[cfg(feature = "conversions")]
+fn ct_eq_window(bytes: &[u8], offset: usize, other: &[u8]) -> bool {
+    use subtle::{ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess};
+
+    // A saturated end (overflow) is always past `bytes.len()`
+    let end = offset.saturating_add(other.len()) as u64;
+    let in_bounds = !end.ct_gt(&(bytes.len() as u64));
+    let window = other.len() as u64;
+
+    let mut diff = 0u8;
+    for (i, &b) in bytes.iter().enumerate() {
+        let rel = i.wrapping_sub(offset) as u64;
+        let inside = rel.ct_lt(&window);
+        // Out-of-window positions read `other[0]`; the result is masked out below
+        let idx = u64::conditional_select(&0, &rel, inside) as usize;
+        let o = other.get(idx).copied().unwrap_or(0);
+        diff |= u8::conditional_select(&0, &(b ^ o), inside);
+    }
+    (diff.ct_eq(&0) & in_bounds).into()
+}
+
+// OR-accumulate per-byte equality over the whole slice — no early exit
+#[cfg(feature = "conversions")]
+fn ct_contains_byte(bytes: &[u8], byte: u8) -> bool {
+    use subtle::ConstantTimeEq;
+
+    let mut found = subtle::Choice::from(0);
+    for b in bytes {
+        found |= b.ct_eq(&byte);
+    }
+    found.into()
+}
+
+// OR-accumulate constant-time equality over every window — no early exit
+#[cfg(feature = "conversions")]
+fn ct_contains_slice(bytes: &[u8], window: &[u8]) -> bool {
+    // `windows(0)` would panic; the empty pattern trivially matches
+    if window.is_empty() {
+        return true;
+    }
+    let mut found = subtle::Choice::from(0);
+    for candidate in bytes.windows(window.len()) {
+        found |= subtle::ConstantTimeEq::ct_eq(candidate, window);
+    }
+    found.into()
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -116,7 +387,7 @@ impl HexString {
     ///
     /// # Errors
     ///
-    /// Returns `Err("invalid hex string")` if validation fails.
+    /// Returns `SecureGateError::InvalidHex` if validation fails.
     ///
     /// # Example
     ///
@@ -125,45 +396,117 @@ impl HexString {
     /// let valid = HexString::new("deadbeef".to_string()).unwrap();
     /// assert_eq!(valid.expose_secret(), "deadbeef");
     /// ```
-    pub fn new(mut s: String) -> Result<Self, &'static str> {
+    pub fn new(mut s: String) -> Result<Self, SecureGateError> {
         // Fast early check – hex strings must have even length
         if s.len() % 2 != 0 {
             zeroize_input(&mut s);
-            return Err("invalid hex string");
+            return Err(SecureGateError::InvalidHex);
         }
 
         // Work directly on the underlying bytes – no copies
         // SAFETY: `String::as_mut_vec()` is unstable but safe. We only modify bytes
         // within the string's valid UTF-8 range, and we validate all changes maintain
         // UTF-8 validity (only ASCII hex digits are modified).
-        let bytes = unsafe { s.as_mut_vec() };
-        let mut valid = true;
-        for b in bytes.iter_mut() {
-            match *b {
-                b'A'..=b'F' => *b += 32, // 'A' → 'a'
-                b'a'..=b'f' | b'0'..=b'9' => {}
-                _ => valid = false,
-            }
-        }
+        let valid = normalize_hex(unsafe { s.as_mut_vec() });
 
         if valid {
             Ok(Self(crate::Dynamic::new(s)))
         } else {
             zeroize_input(&mut s);
-            Err("invalid hex string")
+            Err(SecureGateError::InvalidHex)
+        }
+    }
+
+    /// Create a new `HexString` from a borrowed `&str`.
+    ///
+    /// The input is validated first, without allocating; the owned lowercase
+    /// `String` is only allocated once validation succeeds. Prefer this over
+    /// [`new`](Self::new) when the input is not already an owned `String`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecureGateError::InvalidHex` if validation fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::conversions::HexString;
+    /// let hex = HexString::try_from_str("DEADbeef").unwrap();
+    /// assert_eq!(hex.expose_secret(), "deadbeef");
+    /// assert!(HexString::try_from_str("xyz").is_err());
+    /// ```
+    pub fn try_from_str(s: &str) -> Result<Self, SecureGateError> {
+        if cfg!(feature = "ct-encoding") {
+            // Constant-time validation has no early exit, so copy first and let
+            // `new` validate (and wipe on failure)
+            return Self::new(String::from(s));
+        }
+        if s.len() % 2 != 0 || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(SecureGateError::InvalidHex);
         }
+        Ok(Self(crate::Dynamic::new(s.to_ascii_lowercase())))
+    }
+
+    /// Hex-encode raw secret bytes (lowercase) into a `HexString`.
+    ///
+    /// Valid by construction, so no error path — the inverse of
+    /// [`to_bytes`](Self::to_bytes). The encoded form is secret-derived and is
+    /// wrapped immediately, with the same redaction and wiping as any `HexString`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::conversions::HexString;
+    /// let hex = HexString::from_bytes(&[0xDE, 0xAD, 0xBE, 0xEF]);
+    /// assert_eq!(hex.expose_secret(), "deadbeef");
+    /// assert_eq!(hex.to_bytes(), [0xDE, 0xAD, 0xBE, 0xEF]);
+    /// ```
+    #[must_use = "dropping a converted secret — did you mean to use it?"]
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self(crate::Dynamic::new(encode_hex(bytes, false)))
     }
 
     /// Decode the validated hex string back into raw bytes.
     ///
     /// Panics if the internal string is somehow invalid (impossible under correct usage).
+    #[must_use = "dropping a converted secret — did you mean to use it?"]
     pub fn to_bytes(&self) -> Vec<u8> {
         hex::decode(self.0.expose_secret()).expect("HexString is always valid")
     }
 
+    /// Decode back into raw bytes without table lookups or data-dependent branches.
+    ///
+    /// Same output as [`to_bytes`](Self::to_bytes), but every byte is computed
+    /// arithmetically from its two digits, so timing does not depend on the digit
+    /// values. Prefer this when the decoded bytes feed straight into a comparison.
+    /// The string is validated on construction, so there is no error path.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::conversions::HexString;
+    /// let token = HexString::new("00ff7a".to_string()).unwrap();
+    /// assert_eq!(token.to_bytes_ct(), vec![0x00, 0xff, 0x7a]);
+    /// assert_eq!(token.to_bytes_ct(), token.to_bytes());
+    /// ```
+    #[must_use = "dropping a converted secret — did you mean to use it?"]
+    pub fn to_bytes_ct(&self) -> Vec<u8> {
+        ct_decode_hex(self.0.expose_secret().as_bytes())
+    }
+
     /// Number of bytes the decoded hex string represents.
     pub const fn byte_len(&self) -> usize {
-        self.0.expose_secret().len() / 2
+        self.0.len() / 2
+    }
+}
+
+#[cfg(feature = "conversions")]
+impl TryFrom<&str> for HexString {
+    type Error = SecureGateError;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::try_from_str(s)
     }
 }
 
@@ -201,6 +544,146 @@ impl PartialEq for HexString {
 #[cfg(feature = "conversions")]
 impl Eq for HexString {}
 
+// Constant-time lexicographic ordering – lets `HexString` live in sorted collections
+// (e.g. `BTreeSet`) without leaking the position of the first differing byte.
+// Deliberately slower than a naive compare: every byte of the common prefix is visited.
+#[cfg(feature = "conversions")]
+impl Ord for HexString {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        use subtle::{Choice, ConstantTimeGreater, ConstantTimeLess};
+
+        let a = self.0.expose_secret().as_bytes();
+        let b = other.0.expose_secret().as_bytes();
+
+        let mut lt = Choice::from(0);
+        let mut gt = Choice::from(0);
+        for (x, y) in a.iter().zip(b) {
+            let undecided = !(lt | gt);
+            lt |= undecided & x.ct_lt(y);
+            gt |= undecided & x.ct_gt(y);
+        }
+
+        if bool::from(lt) {
+            core::cmp::Ordering::Less
+        } else if bool::from(gt) {
+            core::cmp::Ordering::Greater
+        } else {
+            // Common prefix is equal – lengths are public, so a plain compare is fine
+            a.len().cmp(&b.len())
+        }
+    }
+}
+
+#[cfg(feature = "conversions")]
+impl PartialOrd for HexString {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// HexStringBuilder — incremental validation for streamed hex input
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Incrementally builds a [`HexString`] from chunks, validating as it goes.
+///
+/// Each chunk is checked and lowercased on [`push_str`](Self::push_str); the even-length
+/// rule is only enforced by [`finish`](Self::finish), so chunks may split a byte.
+/// On any validation failure the accumulated buffer is wiped (with `zeroize`) and cleared.
+/// With `zeroize`, growing the buffer wipes the outgrown allocation, and a builder
+/// dropped before [`finish`](Self::finish) wipes whatever it had accumulated.
+///
+/// # Example
+///
+/// ```
+/// use secure_gate::conversions::HexStringBuilder;
+/// let mut builder = HexStringBuilder::new();
+/// builder.push_str("DEAD").unwrap();
+/// builder.push_str("bee").unwrap();
+/// builder.push_str("f").unwrap();
+/// let hex = builder.finish().unwrap();
+/// assert_eq!(hex.expose_secret(), "deadbeef");
+/// ```
+#[cfg(feature = "conversions")]
+#[derive(Default)]
+pub struct HexStringBuilder(String);
+
+#[cfg(feature = "conversions")]
+impl HexStringBuilder {
+    /// Create an empty builder.
+    pub const fn new() -> Self {
+        Self(String::new())
+    }
+
+    /// Create an empty builder with room for `capacity` hex characters.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(String::with_capacity(capacity))
+    }
+
+    /// Validate `chunk` and append it, normalized to lowercase.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecureGateError::InvalidHex` if `chunk` contains a non-hex character.
+    /// Everything accumulated so far is discarded (and zeroized with `zeroize`).
+    pub fn push_str(&mut self, chunk: &str) -> Result<(), SecureGateError> {
+        crate::dynamic::secure_reserve_str(&mut self.0, chunk.len());
+        let mut valid = true;
+        for b in chunk.bytes() {
+            let (lower, ok) = normalize_hex_byte(b);
+            valid &= ok;
+            // Masked to ASCII so every byte stays one char and the reservation above
+            // holds; invalid input is wiped below anyway
+            self.0.push((lower & 0x7F) as char);
+        }
+        if !valid {
+            zeroize_input(&mut self.0);
+            self.0.clear();
+            return Err(SecureGateError::InvalidHex);
+        }
+        Ok(())
+    }
+
+    /// Number of hex characters accumulated so far.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if nothing has been accumulated.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Finish building, checking the overall length is even.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecureGateError::InvalidHex` on odd length; the buffer is zeroized
+    /// with `zeroize` before it is dropped.
+    pub fn finish(mut self) -> Result<HexString, SecureGateError> {
+        if self.0.len() % 2 != 0 {
+            zeroize_input(&mut self.0);
+            return Err(SecureGateError::InvalidHex);
+        }
+        Ok(HexString(crate::Dynamic::new(core::mem::take(&mut self.0))))
+    }
+}
+
+// Wipes a builder abandoned mid-stream; `finish` takes the buffer out first.
+#[cfg(all(feature = "conversions", feature = "zeroize"))]
+impl Drop for HexStringBuilder {
+    fn drop(&mut self) {
+        zeroize_input(&mut self.0);
+    }
+}
+
+#[cfg(feature = "conversions")]
+impl core::fmt::Debug for HexStringBuilder {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // RandomHex — only constructible from fresh RNG
 // ─────────────────────────────────────────────────────────────────────────────
@@ -217,6 +700,7 @@ impl RandomHex {
     }
 
     /// Decode the random hex string back into raw bytes.
+    #[must_use = "dropping a converted secret — did you mean to use it?"]
     pub fn to_bytes(&self) -> Vec<u8> {
         self.0.to_bytes()
     }
@@ -225,6 +709,40 @@ impl RandomHex {
     pub const fn byte_len(&self) -> usize {
         self.0.byte_len()
     }
+
+    /// Constant-time check of a user-supplied hex string (e.g. a backup code) against
+    /// this value. The candidate is case-normalized, so `"DEADBEEF"` matches `"deadbeef"`.
+    ///
+    /// Runtime depends only on the candidate's length; a length mismatch returns
+    /// `false` without an early exit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::{fixed_alias_rng, conversions::RandomHex};
+    /// fixed_alias_rng!(pub BackupCode, 8);
+    /// let code: RandomHex = BackupCode::random_hex();
+    /// let typed_by_user = code.expose_secret().to_uppercase();
+    /// assert!(code.ct_eq_str(&typed_by_user));
+    /// assert!(!code.ct_eq_str("0000"));
+    /// # }
+    /// ```
+    pub fn ct_eq_str(&self, candidate: &str) -> bool {
+        use subtle::ConstantTimeEq;
+
+        let stored = self.0 .0.expose_secret().as_bytes();
+        let candidate = candidate.as_bytes();
+
+        let mut eq = (stored.len() as u64).ct_eq(&(candidate.len() as u64));
+        let stored = if stored.is_empty() { &[0u8][..] } else { stored };
+        for (i, c) in candidate.iter().enumerate() {
+            let s = stored[crate::ct::ct_clamp_index(i, stored.len())];
+            eq &= s.ct_eq(&c.to_ascii_lowercase());
+        }
+        eq.into()
+    }
 }
 
 #[cfg(all(feature = "rand", feature = "conversions"))]
@@ -260,11 +778,40 @@ impl<const N: usize> crate::rng::FixedRng<N> {
     /// let hex: RandomHex = BackupCode::random_hex();
     /// println!("backup code: {}", hex.expose_secret());
     /// ```
+    #[must_use = "dropping a freshly generated secret — did you mean to use it?"]
     pub fn random_hex() -> RandomHex {
         let hex = {
             let fresh_rng = Self::generate();
-            hex::encode(fresh_rng.expose_secret())
+            encode_hex(fresh_rng.expose_secret(), false)
         }; // fresh_rng dropped and zeroized here
         RandomHex::new_fresh(HexString(crate::Dynamic::new(hex)))
     }
 }
+
+// Serde integration — hex text on the wire, validated on the way in
+#[cfg(all(feature = "serde", feature = "conversions"))]
+impl serde::Serialize for HexString {
+    /// Serializes as the lowercase hex string.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0.expose_secret())
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "conversions"))]
+impl<'de> serde::Deserialize<'de> for HexString {
+    /// Deserializes a string and validates it with [`HexString::new`], so malformed
+    /// input is rejected (and wiped under `zeroize`) rather than wrapped.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        HexString::new(s).map_err(serde::de::Error::custom)
+    }
+}
+
+// Serialize only: a `RandomHex` must come from the RNG, never from input
+#[cfg(all(feature = "serde", feature = "rand", feature = "conversions"))]
+impl serde::Serialize for RandomHex {
+    /// Serializes as the lowercase hex string, like [`HexString`].
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
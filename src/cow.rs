@@ -0,0 +1,124 @@
+// ==========================================================================
+// src/cow.rs
+// ==========================================================================
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{Dynamic, Fixed};
+
+/// Borrowed-or-owned secret bytes — `Cow` for the wrapper world.
+///
+/// Lets an API accept either a secret the caller keeps (borrowed from a `Fixed`
+/// or `Dynamic`) or one it hands over (an owned `Dynamic<Vec<u8>>`) without
+/// forcing a clone. The representation is private: build one with `From` and
+/// read it through [`expose_secret`](Self::expose_secret). A borrowed value
+/// holds the wrapper itself, so each read is a read of the owner's secret.
+///
+/// `Debug` is always redacted. Zeroization only touches owned bytes;
+/// borrowed bytes belong to (and are wiped by) their owner.
+///
+/// # Example
+///
+/// ```
+/// use secure_gate::{Dynamic, Fixed, SecretCow};
+///
+/// fn key_len(key: SecretCow<'_>) -> usize {
+///     key.expose_secret().len()
+/// }
+///
+/// let kept = Fixed::new([7u8; 32]);
+/// assert_eq!(key_len((&kept).into()), 32);
+/// assert_eq!(key_len(Dynamic::<Vec<u8>>::new(vec![1, 2, 3]).into()), 3);
+/// assert_eq!(format!("{:?}", SecretCow::from(&kept)), "[REDACTED]");
+/// ```
+pub struct SecretCow<'a>(Repr<'a>);
+
+// Private so callers cannot match the bytes out — `expose_secret()` is the only read path.
+enum Repr<'a> {
+    Borrowed(&'a dyn BorrowedSecret),
+    Owned(Dynamic<Vec<u8>>),
+}
+
+/// The wrappers a `SecretCow` can borrow; reads go through their own counted `expose()` view.
+trait BorrowedSecret {
+    fn expose_bytes(&self) -> &[u8];
+}
+
+impl<const N: usize> BorrowedSecret for Fixed<[u8; N]> {
+    #[inline]
+    fn expose_bytes(&self) -> &[u8] {
+        self.expose().0
+    }
+}
+
+impl BorrowedSecret for Dynamic<Vec<u8>> {
+    #[inline]
+    fn expose_bytes(&self) -> &[u8] {
+        self.expose().0
+    }
+}
+
+impl SecretCow<'_> {
+    /// Expose the secret bytes, whether borrowed or owned.
+    #[inline]
+    pub fn expose_secret(&self) -> &[u8] {
+        match &self.0 {
+            Repr::Borrowed(secret) => secret.expose_bytes(),
+            Repr::Owned(secret) => secret.expose_secret(),
+        }
+    }
+
+    /// Returns `true` if the bytes are borrowed from a secret the caller still owns.
+    #[inline]
+    pub const fn is_borrowed(&self) -> bool {
+        matches!(self.0, Repr::Borrowed(_))
+    }
+
+    /// Convert into an owned secret, copying only if borrowed.
+    #[must_use = "dropping a derived secret — did you mean to use it?"]
+    pub fn into_owned(self) -> Dynamic<Vec<u8>> {
+        match self.0 {
+            Repr::Borrowed(secret) => Dynamic::new_boxed(Box::new(secret.expose_bytes().to_vec())),
+            Repr::Owned(secret) => secret,
+        }
+    }
+}
+
+impl<'a, const N: usize> From<&'a Fixed<[u8; N]>> for SecretCow<'a> {
+    #[inline]
+    fn from(secret: &'a Fixed<[u8; N]>) -> Self {
+        Self(Repr::Borrowed(secret))
+    }
+}
+
+impl<'a> From<&'a Dynamic<Vec<u8>>> for SecretCow<'a> {
+    #[inline]
+    fn from(secret: &'a Dynamic<Vec<u8>>) -> Self {
+        Self(Repr::Borrowed(secret))
+    }
+}
+
+impl From<Dynamic<Vec<u8>>> for SecretCow<'_> {
+    #[inline]
+    fn from(secret: Dynamic<Vec<u8>>) -> Self {
+        Self(Repr::Owned(secret))
+    }
+}
+
+impl fmt::Debug for SecretCow<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+// Zeroize integration — owned bytes only; borrowed bytes are their owner's to wipe
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for SecretCow<'_> {
+    fn zeroize(&mut self) {
+        if let Repr::Owned(secret) = &mut self.0 {
+            zeroize::Zeroize::zeroize(secret);
+        }
+    }
+}
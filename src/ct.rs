@@ -0,0 +1,123 @@
+// ==========================================================================
+// src/ct.rs
+// ==========================================================================
+
+use subtle::{Choice, ConstantTimeEq};
+
+/// Constant-time equality for byte slices of possibly different lengths.
+///
+/// Runtime depends only on `a.len()`: every byte of `a` is compared against `b`
+/// (past the end of `b`, against its last byte, picked with a masked select), and the
+/// length check is folded in at the end, so a length mismatch returns `false` without
+/// an early exit that would reveal it.
+///
+/// This is the primitive behind the type-specific `ct_eq` helpers; use it
+/// directly for generic verification code (e.g. comparing a `Fixed<[u8; 32]>`
+/// with a `Fixed<[u8; 16]>`). Put the attacker-controlled input in `a`.
+///
+/// Requires the `ct-eq` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "ct-eq")]
+/// # {
+/// use secure_gate::{ct_eq_bytes, Fixed};
+/// let long = Fixed::new([1u8; 32]);
+/// let short = Fixed::new([1u8; 16]);
+/// assert!(!ct_eq_bytes(long.expose_secret(), short.expose_secret()));
+/// assert!(ct_eq_bytes(&[1, 2, 3], &[1, 2, 3]));
+/// # }
+/// ```
+pub fn ct_eq_bytes(a: &[u8], b: &[u8]) -> bool {
+    ct_eq_choice(a, b).into()
+}
+
+/// Constant-time membership test: does `needle` equal any entry of `haystack`?
+///
+/// Every entry is compared in full (via the same primitive as [`ct_eq_bytes`]) and
+/// the results are OR-accumulated — there is no early exit on a match, so timing
+/// depends only on `needle.len()` and `haystack.len()`, never on whether or where
+/// a match occurred. The index of the match is deliberately not returned. Meant
+/// for small sets, e.g. trial verification against several stored keys.
+///
+/// Requires the `ct-eq` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "ct-eq")]
+/// # {
+/// use secure_gate::ct_any_eq;
+/// let tag = [7u8; 16];
+/// let keys: [&[u8]; 3] = [&[1u8; 16], &[7u8; 16], &[9u8; 16]];
+/// assert!(ct_any_eq(&tag, &keys));
+/// assert!(!ct_any_eq(&[0u8; 16], &keys));
+/// assert!(!ct_any_eq(&tag, &[]));
+/// # }
+/// ```
+pub fn ct_any_eq(needle: &[u8], haystack: &[&[u8]]) -> bool {
+    let mut any = Choice::from(0);
+    for candidate in haystack {
+        any |= ct_eq_choice(needle, candidate);
+    }
+    any.into()
+}
+
+// `ct_eq_bytes` without the final conversion, so results can be combined
+fn ct_eq_choice(a: &[u8], b: &[u8]) -> Choice {
+    let mut eq = (a.len() as u64).ct_eq(&(b.len() as u64));
+    // An empty `b` stands in as one zero byte, so the loop below is the same for every `b`
+    let b = if b.is_empty() { &[0u8][..] } else { b };
+    for (i, x) in a.iter().enumerate() {
+        eq &= x.ct_eq(&b[ct_clamp_index(i, b.len())]);
+    }
+    eq
+}
+
+// `min(i, len - 1)` via a masked select rather than `%` (a division by a secret length)
+// or a branch. `len` must be non-zero.
+#[inline]
+pub(crate) fn ct_clamp_index(i: usize, len: usize) -> usize {
+    use subtle::{ConditionallySelectable, ConstantTimeGreater};
+    let (i, last) = (i as u64, (len - 1) as u64);
+    u64::conditional_select(&i, &last, i.ct_gt(&last)) as usize
+}
+
+/// Constant-time comparison between two secrets of the same type.
+///
+/// Backs [`secure_compare!`](crate::secure_compare); not part of the stable API.
+#[doc(hidden)]
+pub trait SecureCompare {
+    fn secure_compare(&self, other: &Self) -> bool;
+}
+
+impl<const N: usize> SecureCompare for crate::Fixed<[u8; N]> {
+    #[inline]
+    fn secure_compare(&self, other: &Self) -> bool {
+        self.ct_eq(other)
+    }
+}
+
+impl<const N: usize> SecureCompare for crate::FixedNoClone<[u8; N]> {
+    #[inline]
+    fn secure_compare(&self, other: &Self) -> bool {
+        self.ct_eq(other)
+    }
+}
+
+impl<T: ?Sized + AsRef<[u8]>> SecureCompare for crate::Dynamic<T> {
+    #[inline]
+    fn secure_compare(&self, other: &Self) -> bool {
+        self.ct_eq(other)
+    }
+}
+
+// `HexString` equality is already constant-time
+#[cfg(feature = "conversions")]
+impl SecureCompare for crate::HexString {
+    #[inline]
+    fn secure_compare(&self, other: &Self) -> bool {
+        self == other
+    }
+}
@@ -5,6 +5,9 @@
 extern crate alloc;
 
 use alloc::boxed::Box;
+use alloc::collections::TryReserveError;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 /// Heap-allocated secure secret wrapper.
 ///
@@ -48,6 +51,7 @@ impl<T: ?Sized> Dynamic<T> {
     /// Wrap an already-boxed value.
     ///
     /// Zero-cost — just wraps the `Box`.
+    #[must_use = "dropping a newly wrapped secret — did you mean to bind it?"]
     #[inline(always)]
     pub fn new_boxed(value: Box<T>) -> Self {
         Dynamic(value)
@@ -56,12 +60,13 @@ impl<T: ?Sized> Dynamic<T> {
     /// Wrap a value by boxing it.
     ///
     /// Uses `Into<Box<T>>` for flexibility.
+    #[must_use = "dropping a newly wrapped secret — did you mean to bind it?"]
     #[inline(always)]
     pub fn new<U>(value: U) -> Self
     where
         U: Into<Box<T>>,
     {
-        Dynamic(value.into())
+        Dynamic::new_boxed(value.into())
     }
 
     /// Expose the inner value for read-only access.
@@ -76,10 +81,70 @@ impl<T: ?Sized> Dynamic<T> {
     ///
     /// This is the **only** way to mutate the secret — loud and auditable.
     #[inline(always)]
+    #[cfg_attr(feature = "tracing", track_caller)]
     pub fn expose_secret_mut(&mut self) -> &mut T {
+        crate::exposure_count::record(&*self.0);
+        #[cfg(feature = "tracing")]
+        crate::exposure_trace::mutable_exposure("Dynamic", &*self.0);
         &mut self.0
     }
 
+    /// Expose the secret through a typed read-only [`Expose`](crate::Expose) view.
+    #[inline(always)]
+    pub fn expose(&self) -> crate::Expose<'_, T> {
+        crate::exposure_count::record(&*self.0);
+        crate::Expose(self.expose_secret())
+    }
+
+    /// Expose the secret through a typed mutable [`ExposeMut`](crate::ExposeMut) view.
+    #[inline(always)]
+    #[cfg_attr(feature = "tracing", track_caller)]
+    pub fn expose_mut(&mut self) -> crate::ExposeMut<'_, T> {
+        crate::ExposeMut(self.expose_secret_mut())
+    }
+
+    /// Replace the secret with a new value, returning the previous one still wrapped.
+    ///
+    /// Only the boxes are swapped — the old secret never leaves its heap allocation,
+    /// so it can be decommissioned (and, with `zeroize`, wiped) as a normal `Dynamic`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut key = Dynamic::<Vec<u8>>::new(vec![1u8; 32]);
+    /// let old = key.replace(vec![2u8; 32]);
+    /// assert_eq!(old.expose_secret(), &[1u8; 32]);
+    /// assert_eq!(key.expose_secret(), &[2u8; 32]);
+    /// ```
+    #[inline]
+    pub fn replace(&mut self, new: T) -> Dynamic<T>
+    where
+        T: Sized,
+    {
+        Dynamic::new_boxed(core::mem::replace(&mut self.0, Box::new(new)))
+    }
+
+    /// Transform the boxed secret into another boxed secret, re-wrapping the result.
+    ///
+    /// The raw `Box` is only visible inside `f`, so the transformation has a single
+    /// auditable call site and no bare secret lingers in the surrounding code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let bytes = Dynamic::<Vec<u8>>::new(b"hunter2".to_vec());
+    /// let text: Dynamic<String> = bytes.map_boxed(|b| {
+    ///     Box::new(String::from_utf8(*b).expect("valid UTF-8"))
+    /// });
+    /// assert_eq!(text.expose_secret(), "hunter2");
+    /// ```
+    #[must_use = "dropping a derived secret — did you mean to use it?"]
+    #[inline]
+    pub fn map_boxed<U: ?Sized>(self, f: impl FnOnce(Box<T>) -> Box<U>) -> Dynamic<U> {
+        Dynamic::new_boxed(f(self.0))
+    }
 
     /// Convert to a non-cloneable variant.
     ///
@@ -93,10 +158,33 @@ impl<T: ?Sized> Dynamic<T> {
     /// let no_clone: DynamicNoClone<String> = secret.no_clone();
     /// assert_eq!(no_clone.expose_secret(), "no copy");
     /// ```
+    #[must_use = "dropping a derived secret — did you mean to use it?"]
     #[inline(always)]
     pub fn no_clone(self) -> crate::DynamicNoClone<T> {
         crate::DynamicNoClone::new(self.0)
     }
+
+    /// Duplicate the secret — identical to `.clone()`, but named for audits.
+    ///
+    /// Prefer this over `.clone()` so every intentional secret copy in a codebase
+    /// can be found with a single grep for `clone_secret`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let pw: Dynamic<String> = "hunter2".into();
+    /// let copy = pw.clone_secret(); // intentional, grep-able duplication
+    /// assert_eq!(copy.expose_secret(), "hunter2");
+    /// ```
+    #[must_use = "dropping a derived secret — did you mean to use it?"]
+    #[inline(always)]
+    pub fn clone_secret(&self) -> Self
+    where
+        Self: Clone,
+    {
+        self.clone()
+    }
 }
 
 // Explicit zeroization — only available with `zeroize` feature
@@ -122,6 +210,180 @@ impl<T: ?Sized + zeroize::Zeroize> Dynamic<T> {
     pub fn zeroize_now(&mut self) {
         self.0.zeroize();
     }
+
+    /// Copy the secret into a `Zeroizing` wrapper that wipes itself on drop.
+    ///
+    /// For handing a throwaway copy to an API that consumes it — shorter than
+    /// `Zeroizing::new(secret.expose_secret().clone())`, and the copy never
+    /// exists outside a wiping wrapper. Works for `String` and `Vec<u8>` alike.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "zeroize")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let pw: Dynamic<String> = "hunter2".into();
+    /// let tmp = pw.zeroizing_clone(); // wiped when `tmp` drops
+    /// assert_eq!(tmp.as_str(), "hunter2");
+    ///
+    /// let key: Dynamic<Vec<u8>> = Dynamic::new(vec![1, 2, 3]);
+    /// assert_eq!(*key.zeroizing_clone(), [1, 2, 3]);
+    /// # }
+    /// ```
+    #[must_use = "dropping a derived secret — did you mean to use it?"]
+    #[inline]
+    pub fn zeroizing_clone(&self) -> zeroize::Zeroizing<T>
+    where
+        T: Clone,
+    {
+        zeroize::Zeroizing::new((*self.0).clone())
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Dynamic<Vec<u8>> {
+    /// Wipe the secret and release its allocation.
+    ///
+    /// Unlike [`zeroize_now`](Self::zeroize_now), which wipes in place but keeps the
+    /// capacity, this zeroizes the whole buffer (including spare capacity) and then
+    /// swaps in a fresh empty `Vec`, returning the wiped memory to the allocator.
+    /// Useful for long-lived stores whose buffer was temporarily large.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "zeroize")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let mut buf = Dynamic::<Vec<u8>>::new(vec![0xAA; 4096]);
+    /// buf.secure_clear();
+    /// assert!(buf.is_empty());
+    /// assert_eq!(buf.expose_secret().capacity(), 0);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn secure_clear(&mut self) {
+        zeroize::Zeroize::zeroize(&mut *self.0);
+        *self.0 = Vec::new();
+    }
+
+    /// Zeroize only the bytes in `range`, keeping the rest of the buffer intact.
+    ///
+    /// For buffers holding several fields where one goes stale (e.g. a used nonce)
+    /// while the others are still needed. The length is unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecureGateError::OutOfBounds` if the range ends past the buffer or
+    /// starts after it ends; nothing is wiped in that case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "zeroize")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let mut buf = Dynamic::<Vec<u8>>::new(vec![1, 2, 3, 4, 5]);
+    /// buf.zeroize_range(1..3).unwrap();
+    /// assert_eq!(buf.expose_secret(), &[1, 0, 0, 4, 5]);
+    /// # }
+    /// ```
+    pub fn zeroize_range(
+        &mut self,
+        range: core::ops::Range<usize>,
+    ) -> Result<(), crate::SecureGateError> {
+        let len = self.0.len();
+        if range.end > len {
+            return Err(crate::SecureGateError::OutOfBounds {
+                index: range.end,
+                len,
+            });
+        }
+        if range.start > range.end {
+            return Err(crate::SecureGateError::OutOfBounds {
+                index: range.start,
+                len,
+            });
+        }
+        zeroize::Zeroize::zeroize(&mut self.0[range]);
+        Ok(())
+    }
+
+    /// Stream the secret out in `size`-byte chunks, consuming the buffer as it goes.
+    ///
+    /// Each chunk is copied into a `Zeroizing<Vec<u8>>` (wiped when the consumer
+    /// drops it) and its source region is zeroized immediately. The final chunk may
+    /// be shorter than `size`. If the iterator is dropped early, the unread remainder
+    /// is moved to the front of the buffer and the vacated tail is wiped, so no copy
+    /// of a yielded chunk survives in the secret either.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "zeroize")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let mut buf = Dynamic::<Vec<u8>>::new((0u8..10).collect::<Vec<u8>>());
+    /// let chunks: Vec<_> = buf.drain_chunks_zeroizing(4).map(|c| c.len()).collect();
+    /// assert_eq!(chunks, [4, 4, 2]);
+    /// assert!(buf.is_empty());
+    /// # }
+    /// ```
+    pub fn drain_chunks_zeroizing(
+        &mut self,
+        size: usize,
+    ) -> impl Iterator<Item = zeroize::Zeroizing<Vec<u8>>> + '_ {
+        assert!(
+            size != 0,
+            "drain_chunks_zeroizing: chunk size must be non-zero"
+        );
+        ZeroizingChunks {
+            buf: &mut self.0,
+            size,
+            offset: 0,
+        }
+    }
+}
+
+// Iterator behind `drain_chunks_zeroizing` — `offset` marks the already-wiped prefix
+#[cfg(feature = "zeroize")]
+struct ZeroizingChunks<'a> {
+    buf: &'a mut Vec<u8>,
+    size: usize,
+    offset: usize,
+}
+
+#[cfg(feature = "zeroize")]
+impl Iterator for ZeroizingChunks<'_> {
+    type Item = zeroize::Zeroizing<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.buf.len() {
+            return None;
+        }
+        let end = self.buf.len().min(self.offset + self.size);
+        let region = &mut self.buf[self.offset..end];
+        let chunk = zeroize::Zeroizing::new(region.to_vec());
+        zeroize::Zeroize::zeroize(region);
+        self.offset = end;
+        Some(chunk)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for ZeroizingChunks<'_> {
+    fn drop(&mut self) {
+        // Drop the consumed prefix: shift the unread rest forward, wipe what it vacated
+        let remaining = self.buf.len() - self.offset;
+        self.buf.copy_within(self.offset.., 0);
+        zeroize::Zeroize::zeroize(&mut self.buf[remaining..]);
+        self.buf.truncate(remaining);
+    }
 }
 
 impl<T: ?Sized> core::fmt::Debug for Dynamic<T> {
@@ -135,7 +397,7 @@ impl<T: ?Sized> core::fmt::Debug for Dynamic<T> {
 impl<T: Clone> Clone for Dynamic<T> {
     #[inline(always)]
     fn clone(&self) -> Self {
-        Dynamic(self.0.clone())
+        Dynamic::new_boxed(self.0.clone())
     }
 }
 
@@ -143,7 +405,74 @@ impl<T: Clone> Clone for Dynamic<T> {
 impl<T: Clone + zeroize::Zeroize> Clone for Dynamic<T> {
     #[inline(always)]
     fn clone(&self) -> Self {
-        Dynamic(self.0.clone())
+        Dynamic::new_boxed(self.0.clone())
+    }
+}
+
+impl<T: ?Sized + AsRef<[u8]>> Dynamic<T> {
+    /// One-line diagnostic summary: type and byte length, never content.
+    ///
+    /// An explicit opt-in for structured logs — richer than the `[REDACTED]` `Debug`
+    /// output, which is unchanged. The length is public metadata and is read without
+    /// counting as an exposure. The type name comes from `core::any::type_name`
+    /// (paths stripped), so treat it as informational rather than a stable format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let blob = Dynamic::<Vec<u8>>::new(vec![0u8; 32]);
+    /// assert_eq!(blob.redacted_summary(), "Dynamic<Vec<u8>>(len=32)");
+    ///
+    /// let pw: Dynamic<String> = "hunter2".into();
+    /// assert_eq!(pw.redacted_summary(), "Dynamic<String>(len=7)");
+    /// ```
+    pub fn redacted_summary(&self) -> String {
+        crate::redact::redacted_summary::<T>("Dynamic", (*self.0).as_ref().len())
+    }
+
+    /// A `Debug` stand-in that prints `[REDACTED len=N]` with the byte length.
+    ///
+    /// For hand-written `Debug` impls of structs full of secrets. The wrapper's own
+    /// `Debug` stays `[REDACTED]`: lengths can be sensitive (e.g. a password's), so
+    /// they are only shown where a call site asks for them. Reading the length does
+    /// not count as an exposure.
+    ///
+    /// Requires the `debug-len` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "debug-len")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// use std::fmt;
+    ///
+    /// struct Login {
+    ///     user: String,
+    ///     password: Dynamic<String>,
+    /// }
+    ///
+    /// impl fmt::Debug for Login {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         f.debug_struct("Login")
+    ///             .field("user", &self.user)
+    ///             .field("password", &self.password.redacted_debug_with_len())
+    ///             .finish()
+    ///     }
+    /// }
+    ///
+    /// let login = Login { user: "alice".into(), password: "hunter22".into() };
+    /// assert_eq!(
+    ///     format!("{login:?}"),
+    ///     r#"Login { user: "alice", password: [REDACTED len=8] }"#
+    /// );
+    /// # }
+    /// ```
+    #[cfg(feature = "debug-len")]
+    #[inline]
+    pub fn redacted_debug_with_len(&self) -> impl core::fmt::Debug {
+        crate::redact::RedactedLen((*self.0).as_ref().len())
     }
 }
 
@@ -158,89 +487,1322 @@ impl Dynamic<String> {
     pub const fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
-}
 
-impl<T> Dynamic<Vec<T>> {
-    #[inline(always)]
-    pub const fn len(&self) -> usize {
-        self.0.len()
+    /// Append a single character to the secret.
+    ///
+    /// Capacity grows amortized like `String::push`; with `zeroize`, each time the
+    /// buffer has to move, the old allocation is wiped before it is freed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut passphrase: Dynamic<String> = "".into();
+    /// for c in "hunter2".chars() {
+    ///     passphrase.push_char(c);
+    /// }
+    /// assert_eq!(passphrase.expose_secret(), "hunter2");
+    /// ```
+    #[inline]
+    pub fn push_char(&mut self, c: char) {
+        secure_reserve_str(&mut self.0, c.len_utf8());
+        self.0.push(c);
     }
 
-    #[inline(always)]
-    pub const fn is_empty(&self) -> bool {
-        self.0.is_empty()
+    /// Append a non-secret string slice (e.g. a separator) to the secret.
+    ///
+    /// Grows capacity the same way as [`push_char`](Self::push_char).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut creds: Dynamic<String> = "alice".into();
+    /// creds.push_str(":");
+    /// assert_eq!(creds.expose_secret(), "alice:");
+    /// ```
+    #[inline]
+    pub fn push_str(&mut self, s: &str) {
+        secure_reserve_str(&mut self.0, s.len());
+        self.0.push_str(s);
     }
-}
 
-// === Convenient From impls ===
-impl<T> From<T> for Dynamic<T> {
-    #[inline(always)]
-    fn from(value: T) -> Self {
-        Self(Box::new(value))
+    /// Append another secret string without exposing either buffer to the caller.
+    ///
+    /// Useful for building composite credentials while both operands stay wrapped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut creds: Dynamic<String> = "alice".into();
+    /// let password: Dynamic<String> = "hunter2".into();
+    /// creds.push_str(":");
+    /// creds.push_secret(&password);
+    /// assert_eq!(creds.expose_secret(), "alice:hunter2");
+    /// ```
+    #[inline]
+    pub fn push_secret(&mut self, other: &Dynamic<String>) {
+        secure_reserve_str(&mut self.0, other.0.len());
+        self.0.push_str(&other.0);
     }
-}
 
-impl<T: ?Sized> From<Box<T>> for Dynamic<T> {
-    #[inline(always)]
-    fn from(boxed: Box<T>) -> Self {
-        Self(boxed)
+    /// Shorten the secret to `new_len` bytes, wiping the removed tail first.
+    ///
+    /// `String::truncate` leaves the dropped bytes in spare capacity; with `zeroize`
+    /// this overwrites `[new_len, len)` before truncating so no secret suffix lingers.
+    /// Does nothing if `new_len >= len()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` does not lie on a `char` boundary, like `String::truncate`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut pw: Dynamic<String> = "hunter2-extra".into();
+    /// pw.truncate_secure(7);
+    /// assert_eq!(pw.expose_secret(), "hunter2");
+    /// ```
+    pub fn truncate_secure(&mut self, new_len: usize) {
+        if new_len >= self.0.len() {
+            return;
+        }
+        assert!(
+            self.0.is_char_boundary(new_len),
+            "truncate_secure: new_len is not on a char boundary"
+        );
+        #[cfg(feature = "zeroize")]
+        {
+            // SAFETY: the tail is overwritten with zero bytes (valid ASCII NUL) and
+            // then cut off, so the string stays valid UTF-8 throughout.
+            let bytes = unsafe { self.0.as_mut_vec() };
+            zeroize::Zeroize::zeroize(&mut bytes[new_len..]);
+        }
+        self.0.truncate(new_len);
     }
-}
 
-impl From<&str> for Dynamic<String> {
-    #[inline(always)]
-    fn from(s: &str) -> Self {
-        Self(Box::new(s.to_string()))
+    /// Like [`truncate_secure`](Self::truncate_secure), but rejects bad lengths
+    /// instead of ignoring or panicking on them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecureGateError::OutOfBounds` if `new_len > len()` and
+    /// `SecureGateError::InvalidUtf8` if `new_len` would split a character; the
+    /// secret is left unchanged in both cases.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut pw: Dynamic<String> = "pässword".into();
+    /// assert!(pw.checked_truncate(2).is_err()); // inside 'ä'
+    /// assert!(pw.checked_truncate(20).is_err());
+    /// pw.checked_truncate(3).unwrap();
+    /// assert_eq!(pw.expose_secret(), "pä");
+    /// ```
+    pub fn checked_truncate(&mut self, new_len: usize) -> Result<(), crate::SecureGateError> {
+        if new_len > self.0.len() {
+            return Err(crate::SecureGateError::OutOfBounds {
+                index: new_len,
+                len: self.0.len(),
+            });
+        }
+        if !self.0.is_char_boundary(new_len) {
+            return Err(crate::SecureGateError::InvalidUtf8);
+        }
+        self.truncate_secure(new_len);
+        Ok(())
     }
-}
 
-// Constant-time equality — only available with `conversions` feature
-#[cfg(feature = "conversions")]
-impl<T> Dynamic<T>
-where
-    T: ?Sized + AsRef<[u8]>,
-{
+    /// Lowercase ASCII letters in place; non-ASCII characters are left unchanged.
+    ///
+    /// For normalizing case-insensitive secrets (e.g. recovery phrases) right before
+    /// a constant-time comparison, without exposing the string. Works on the
+    /// existing buffer, so no unwiped copy is created.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut phrase: Dynamic<String> = "Correct Horse".into();
+    /// phrase.make_ascii_lowercase();
+    /// assert_eq!(phrase.expose_secret(), "correct horse");
+    /// ```
     #[inline]
-    pub fn ct_eq(&self, other: &Self) -> bool {
-        use crate::conversions::SecureConversionsExt;
-        self.expose_secret()
-            .as_ref()
-            .ct_eq(other.expose_secret().as_ref())
+    pub fn make_ascii_lowercase(&mut self) {
+        self.0.make_ascii_lowercase();
     }
-}
 
-// Random generation — only available with `rand` feature
-#[cfg(feature = "rand")]
-impl Dynamic<Vec<u8>> {
-    /// Generate fresh random bytes of the specified length using the OS RNG.
+    /// Uppercase ASCII letters in place; see [`make_ascii_lowercase`](Self::make_ascii_lowercase).
     ///
-    /// This is a convenience method that generates random bytes directly
-    /// without going through `DynamicRng`. Equivalent to:
-    /// `DynamicRng::generate(len).into_inner()`
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut code: Dynamic<String> = "ab-12".into();
+    /// code.make_ascii_uppercase();
+    /// assert_eq!(code.expose_secret(), "AB-12");
+    /// ```
+    #[inline]
+    pub fn make_ascii_uppercase(&mut self) {
+        self.0.make_ascii_uppercase();
+    }
+
+    /// Parse the secret string into `F`, keeping the result wrapped in a [`Fixed`](crate::Fixed).
+    ///
+    /// The string is exposed only for the duration of the parse (counted as one
+    /// exposure), so a parsed PIN or numeric token never reaches surrounding code as
+    /// a bare value. Note that `F::Err` is returned as-is; standard numeric parse
+    /// errors do not echo the input.
     ///
     /// # Example
     ///
     /// ```
-    /// # #[cfg(feature = "rand")]
-    /// # {
     /// use secure_gate::Dynamic;
-    /// let random: Dynamic<Vec<u8>> = Dynamic::generate_random(64);
-    /// assert_eq!(random.len(), 64);
-    /// # }
+    /// let pin: Dynamic<String> = "482913".into();
+    /// let parsed = pin.parse_secret::<u64>().unwrap();
+    /// assert_eq!(*parsed.expose_secret(), 482_913);
+    /// assert_eq!(format!("{parsed:?}"), "[REDACTED]");
     /// ```
     #[inline]
-    pub fn generate_random(len: usize) -> Self {
-        crate::rng::DynamicRng::generate(len).into_inner()
+    pub fn parse_secret<F: core::str::FromStr>(&self) -> Result<crate::Fixed<F>, F::Err> {
+        self.expose_secret().parse().map(crate::Fixed::new)
+    }
+
+    /// Build a secret string from bytes, replacing invalid UTF-8 with `U+FFFD`.
+    ///
+    /// A best-effort ingestion path for legacy sources: the result is always a
+    /// valid string, but **any replacement alters the secret**, so it is unsuitable
+    /// for exact comparison or verification. Use it only where an approximate,
+    /// display-adjacent string is acceptable. The input bytes are only read; wiping
+    /// them remains the caller's job.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let legacy = Dynamic::<String>::from_utf8_lossy(b"pass\xFFword");
+    /// assert_eq!(legacy.expose_secret(), "pass\u{FFFD}word");
+    /// ```
+    #[must_use = "dropping a newly wrapped secret — did you mean to bind it?"]
+    pub fn from_utf8_lossy(bytes: &[u8]) -> Self {
+        Self::new_boxed(Box::new(String::from_utf8_lossy(bytes).into_owned()))
     }
 }
 
-// Zeroize integration
-#[cfg(feature = "zeroize")]
-impl<T: ?Sized + zeroize::Zeroize> zeroize::Zeroize for Dynamic<T> {
-    fn zeroize(&mut self) {
-        self.0.zeroize();
+// `secure_reserve` for a `String`: the same amortized growth, with the outgrown
+// allocation wiped under `zeroize`.
+#[inline]
+pub(crate) fn secure_reserve_str(s: &mut alloc::string::String, additional: usize) {
+    #[cfg(feature = "zeroize")]
+    {
+        // SAFETY: `secure_reserve` only changes the capacity, copying the existing
+        // bytes verbatim when it reallocates, so the string stays valid UTF-8.
+        secure_reserve(unsafe { s.as_mut_vec() }, additional, false);
     }
+    #[cfg(not(feature = "zeroize"))]
+    s.reserve(additional);
 }
 
-#[cfg(feature = "zeroize")]
-impl<T: ?Sized + zeroize::Zeroize> zeroize::ZeroizeOnDrop for Dynamic<T> {}
+impl<T> Dynamic<Vec<T>> {
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Dynamic<Vec<u8>> {
+    /// XOR a keystream into the secret bytes in place.
+    ///
+    /// The plaintext never leaves the wrapper — useful for one-time-pad or
+    /// CTR-mode post-processing. Returns `SecureGateError::LengthMismatch` if the
+    /// lengths differ, so a short keystream can never silently leave a tail unmasked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut data = Dynamic::<Vec<u8>>::new(vec![0x0F, 0xF0]);
+    /// data.xor_with(&[0xFF, 0xFF]).unwrap();
+    /// assert_eq!(data.expose_secret(), &[0xF0, 0x0F]);
+    /// assert!(data.xor_with(&[0x00]).is_err());
+    /// ```
+    #[inline]
+    pub fn xor_with(&mut self, keystream: &[u8]) -> Result<(), crate::SecureGateError> {
+        if self.0.len() != keystream.len() {
+            return Err(crate::SecureGateError::LengthMismatch {
+                expected: self.0.len(),
+                got: keystream.len(),
+            });
+        }
+        for (b, k) in self.0.iter_mut().zip(keystream) {
+            *b ^= k;
+        }
+        Ok(())
+    }
+
+    /// Element-wise XOR of two equal-length secrets into a fresh secret.
+    ///
+    /// Neither operand is modified, which suits XOR secret-splitting where both
+    /// shares must be kept. Returns `SecureGateError::LengthMismatch` if the
+    /// lengths differ.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let secret = Dynamic::<Vec<u8>>::new(vec![0x12, 0x34]);
+    /// let share_a = Dynamic::<Vec<u8>>::new(vec![0xFF, 0x00]);
+    /// let share_b = secret.xor(&share_a).unwrap();
+    /// assert_eq!(share_a.xor(&share_b).unwrap().expose_secret(), secret.expose_secret());
+    /// ```
+    pub fn xor(
+        &self,
+        other: &Dynamic<Vec<u8>>,
+    ) -> Result<Dynamic<Vec<u8>>, crate::SecureGateError> {
+        if self.0.len() != other.0.len() {
+            return Err(crate::SecureGateError::LengthMismatch {
+                expected: self.0.len(),
+                got: other.0.len(),
+            });
+        }
+        let out: Vec<u8> = self
+            .0
+            .iter()
+            .zip(other.0.iter())
+            .map(|(a, b)| a ^ b)
+            .collect();
+        Ok(Dynamic::new_boxed(Box::new(out)))
+    }
+
+    /// Copy `[..mid]` and `[mid..]` into two new secrets, leaving `self` intact.
+    ///
+    /// For deriving independent sub-keys (e.g. an encryption key and a MAC key)
+    /// from a combined buffer that must itself be kept. Each half gets its own
+    /// exact-size allocation and is wiped on drop like any other `Dynamic`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecureGateError::OutOfBounds` if `mid > len`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let okm = Dynamic::<Vec<u8>>::new(vec![1, 2, 3, 4, 5, 6]);
+    /// let (enc, mac) = okm.split_copy(4).unwrap();
+    /// assert_eq!(enc.expose_secret(), &[1, 2, 3, 4]);
+    /// assert_eq!(mac.expose_secret(), &[5, 6]);
+    /// assert_eq!(okm.len(), 6);
+    /// assert!(okm.split_copy(7).is_err());
+    /// ```
+    pub fn split_copy(&self, mid: usize) -> Result<(Self, Self), crate::SecureGateError> {
+        if mid > self.0.len() {
+            return Err(crate::SecureGateError::OutOfBounds {
+                index: mid,
+                len: self.0.len(),
+            });
+        }
+        let (head, tail) = self.0.split_at(mid);
+        Ok((
+            Dynamic::new_boxed(Box::new(head.to_vec())),
+            Dynamic::new_boxed(Box::new(tail.to_vec())),
+        ))
+    }
+
+    /// Overwrite the secret in place with the bytes of another secret of equal length.
+    ///
+    /// Reuses `self`'s allocation — nothing is reallocated, so no old buffer is left
+    /// behind — and neither operand is exposed to the caller. Handy for refreshing
+    /// a long-lived buffer from a freshly derived value. Returns
+    /// `SecureGateError::LengthMismatch` (leaving `self` untouched) if the lengths differ.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut session = Dynamic::<Vec<u8>>::new(vec![0u8; 4]);
+    /// let fresh = Dynamic::<Vec<u8>>::new(vec![1, 2, 3, 4]);
+    /// session.copy_from_secret(&fresh).unwrap();
+    /// assert_eq!(session.expose_secret(), &[1, 2, 3, 4]);
+    /// assert!(session.copy_from_secret(&Dynamic::<Vec<u8>>::new(vec![0; 3])).is_err());
+    /// ```
+    #[inline]
+    pub fn copy_from_secret(
+        &mut self,
+        src: &Dynamic<Vec<u8>>,
+    ) -> Result<(), crate::SecureGateError> {
+        if self.0.len() != src.0.len() {
+            return Err(crate::SecureGateError::LengthMismatch {
+                expected: self.0.len(),
+                got: src.0.len(),
+            });
+        }
+        self.0.copy_from_slice(&src.0);
+        Ok(())
+    }
+
+    /// Expose the bytes as a borrowed `&str` after checking they are valid UTF-8.
+    ///
+    /// A zero-copy, read-only view into the existing buffer — nothing is allocated
+    /// or converted, and the exposure ends with the borrow. Counts as an exposure.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `core::str::Utf8Error` from validation if the bytes are not
+    /// valid UTF-8, so callers can see where the valid prefix ends.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let blob = Dynamic::<Vec<u8>>::new(b"hunter2".to_vec());
+    /// assert_eq!(blob.as_str_checked().unwrap(), "hunter2");
+    /// let err = Dynamic::<Vec<u8>>::new(vec![b'a', 0xFF]).as_str_checked().unwrap_err();
+    /// assert_eq!(err.valid_up_to(), 1);
+    /// ```
+    #[inline]
+    pub fn as_str_checked(&self) -> Result<&str, core::str::Utf8Error> {
+        core::str::from_utf8(self.expose_secret())
+    }
+
+    /// Hand the secret bytes over as a bare `Box<[u8]>` — **nothing will wipe them**.
+    ///
+    /// The FFI escape hatch: for passing ownership to foreign code that frees the
+    /// buffer itself. The returned box is outside the wrapper system entirely; wiping
+    /// it before it is freed is now **the caller's responsibility** (or the foreign
+    /// code's). Prefer keeping the `Dynamic` whenever ownership need not leave Rust.
+    ///
+    /// The box holds exactly `len` bytes. If the vector had spare capacity, the bytes
+    /// are copied into an exact-size allocation and (with `zeroize`) the original
+    /// buffer is wiped, rather than shrunk in place by the allocator unwiped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let key = Dynamic::<Vec<u8>>::new(vec![1u8, 2, 3]);
+    /// let mut raw: Box<[u8]> = key.into_boxed_slice_unmanaged();
+    /// assert_eq!(&*raw, &[1, 2, 3]);
+    /// // ... hand `raw` to foreign code, or wipe it yourself:
+    /// raw.fill(0);
+    /// ```
+    #[must_use = "dropping a derived secret — did you mean to use it?"]
+    pub fn into_boxed_slice_unmanaged(self) -> Box<[u8]> {
+        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+        let mut bytes = *self.0;
+        if bytes.capacity() == bytes.len() {
+            return bytes.into_boxed_slice();
+        }
+        let exact = Box::<[u8]>::from(bytes.as_slice());
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut bytes);
+        exact
+    }
+
+    /// Split the secret into independent `N`-byte `Fixed` blocks.
+    ///
+    /// Each block is a copy, so the source can be wiped afterwards while the
+    /// blocks live on — e.g. splitting a derived keystream into per-block keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecureGateError::ChunkMisaligned` if the length isn't a multiple
+    /// of `N` (or `N` is zero).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let stream = Dynamic::<Vec<u8>>::new((0u8..8).collect::<Vec<u8>>());
+    /// let blocks = stream.to_fixed_chunks::<4>().unwrap();
+    /// assert_eq!(blocks.len(), 2);
+    /// assert_eq!(blocks[1].expose_secret(), &[4, 5, 6, 7]);
+    /// assert!(stream.to_fixed_chunks::<3>().is_err());
+    /// ```
+    pub fn to_fixed_chunks<const N: usize>(
+        &self,
+    ) -> Result<Vec<crate::Fixed<[u8; N]>>, crate::SecureGateError> {
+        if self.0.len().checked_rem(N) != Some(0) {
+            return Err(crate::SecureGateError::ChunkMisaligned {
+                chunk_size: N,
+                len: self.0.len(),
+            });
+        }
+        Ok(self
+            .0
+            .chunks_exact(N)
+            .map(|chunk| {
+                let mut block = [0u8; N];
+                block.copy_from_slice(chunk);
+                crate::Fixed::new(block)
+            })
+            .collect())
+    }
+
+    /// Build a secret from an iterator known to yield exactly `len` bytes.
+    ///
+    /// Allocates once, up front, instead of growing like `collect` (under `zeroize`
+    /// each growth step would also copy and wipe). Meant for deterministic
+    /// derivations such as `(0..n).map(derive_byte)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator yields fewer or more than `len` items; the bytes
+    /// gathered so far are wiped first (with `zeroize`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let derived = Dynamic::<Vec<u8>>::from_iter_exact(4, (0..4u8).map(|i| i * 3));
+    /// assert_eq!(derived.expose_secret(), &[0, 3, 6, 9]);
+    /// ```
+    #[must_use = "dropping a newly wrapped secret — did you mean to bind it?"]
+    pub fn from_iter_exact(len: usize, mut iter: impl Iterator<Item = u8>) -> Self {
+        let mut bytes = Vec::with_capacity(len);
+        bytes.extend(iter.by_ref().take(len));
+        if bytes.len() != len || iter.next().is_some() {
+            #[cfg(feature = "zeroize")]
+            zeroize::Zeroize::zeroize(&mut bytes);
+            panic!("from_iter_exact: iterator did not yield exactly {len} items");
+        }
+        Self::new_boxed(Box::new(bytes))
+    }
+
+    /// Keep only the bytes for which `f` returns `true`, wiping the ones removed.
+    ///
+    /// Unlike a bare `Vec::retain`, filtered-out bytes do not linger: retained bytes
+    /// are compacted over them in place, and with `zeroize` the vacated tail is wiped.
+    /// Useful for stripping padding from decrypted data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut data = Dynamic::<Vec<u8>>::new(vec![b'k', 0, b'e', 0, b'y']);
+    /// data.retain_secure(|b| b != 0);
+    /// assert_eq!(data.expose_secret(), b"key");
+    /// ```
+    pub fn retain_secure(&mut self, mut f: impl FnMut(u8) -> bool) {
+        #[cfg(feature = "zeroize")]
+        let old_len = self.0.len();
+        self.0.retain(|&b| f(b));
+        // `retain` compacts in place, so every removed byte now sits in (or was
+        // overwritten before) the vacated region just past the new length
+        #[cfg(feature = "zeroize")]
+        {
+            let vacated = old_len - self.0.len();
+            zeroize::Zeroize::zeroize(&mut self.0.spare_capacity_mut()[..vacated]);
+        }
+    }
+
+    /// Insert `bytes` at the front of the secret, shifting the existing contents.
+    ///
+    /// Handy for framing (length/type prefixes) without exposing the buffer. If the
+    /// buffer has to grow, with `zeroize` the old allocation is wiped before it is freed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut frame = Dynamic::<Vec<u8>>::new(b"payload".to_vec());
+    /// frame.prepend(&[0x00, 0x07]);
+    /// assert_eq!(frame.expose_secret(), b"\x00\x07payload");
+    /// ```
+    pub fn prepend(&mut self, bytes: &[u8]) {
+        secure_reserve(&mut self.0, bytes.len(), false);
+        self.0.extend_from_slice(bytes);
+        self.0.rotate_right(bytes.len());
+    }
+
+    /// Append a copy of another secret's bytes, leaving `other` intact.
+    ///
+    /// For accumulating reusable secret fragments (e.g. into a transcript) without
+    /// exposing either buffer. If `self` has to grow, with `zeroize` the old
+    /// allocation is wiped before it is freed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let label = Dynamic::<Vec<u8>>::new(b"psk".to_vec());
+    /// let mut transcript = Dynamic::<Vec<u8>>::new(Vec::new());
+    /// transcript.extend_from_secret(&label);
+    /// transcript.extend_from_secret(&label);
+    /// assert_eq!(transcript.expose_secret(), b"pskpsk");
+    /// assert_eq!(label.expose_secret(), b"psk");
+    /// ```
+    pub fn extend_from_secret(&mut self, other: &Dynamic<Vec<u8>>) {
+        secure_reserve(&mut self.0, other.0.len(), false);
+        self.0.extend_from_slice(&other.0);
+    }
+
+    /// Shorten the secret to `new_len` bytes, wiping the removed tail first.
+    ///
+    /// Unlike `Vec::truncate`, an over-long `new_len` is reported rather than
+    /// silently ignored, so length bookkeeping mistakes surface. With `zeroize`,
+    /// `[new_len, len)` is overwritten before the length shrinks, so the dropped
+    /// bytes do not linger in spare capacity.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecureGateError::OutOfBounds` if `new_len > len`; the buffer is left
+    /// unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut buf = Dynamic::<Vec<u8>>::new(vec![1, 2, 3, 4]);
+    /// buf.checked_truncate(2).unwrap();
+    /// assert_eq!(buf.expose_secret(), &[1, 2]);
+    /// assert!(buf.checked_truncate(3).is_err());
+    /// ```
+    pub fn checked_truncate(&mut self, new_len: usize) -> Result<(), crate::SecureGateError> {
+        if new_len > self.0.len() {
+            return Err(crate::SecureGateError::OutOfBounds {
+                index: new_len,
+                len: self.0.len(),
+            });
+        }
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut self.0[new_len..]);
+        self.0.truncate(new_len);
+        Ok(())
+    }
+
+    /// Rotate the secret bytes left in place by `mid` positions.
+    ///
+    /// The rotation amount is public; the bytes never leave the wrapper.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecureGateError::OutOfBounds` if `mid > len` (where
+    /// `[u8]::rotate_left` would panic); the buffer is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut data = Dynamic::<Vec<u8>>::new(vec![1, 2, 3, 4]);
+    /// data.rotate_left(1).unwrap();
+    /// assert_eq!(data.expose_secret(), &[2, 3, 4, 1]);
+    /// assert!(data.rotate_left(5).is_err());
+    /// ```
+    pub fn rotate_left(&mut self, mid: usize) -> Result<(), crate::SecureGateError> {
+        self.check_rotation(mid)?;
+        self.0.rotate_left(mid);
+        Ok(())
+    }
+
+    /// Rotate the secret bytes right in place by `k` positions.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecureGateError::OutOfBounds` if `k > len`; the buffer is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut data = Dynamic::<Vec<u8>>::new(vec![1, 2, 3, 4]);
+    /// data.rotate_right(1).unwrap();
+    /// assert_eq!(data.expose_secret(), &[4, 1, 2, 3]);
+    /// ```
+    pub fn rotate_right(&mut self, k: usize) -> Result<(), crate::SecureGateError> {
+        self.check_rotation(k)?;
+        self.0.rotate_right(k);
+        Ok(())
+    }
+
+    #[inline]
+    fn check_rotation(&self, amount: usize) -> Result<(), crate::SecureGateError> {
+        if amount > self.0.len() {
+            return Err(crate::SecureGateError::OutOfBounds {
+                index: amount,
+                len: self.0.len(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Reserve capacity for at least `additional` more bytes.
+    ///
+    /// # Reallocation and `zeroize`
+    ///
+    /// Growing a `Vec` through `expose_secret_mut().reserve(..)` lets the allocator
+    /// free the old buffer **without wiping it**. This method instead, with `zeroize`,
+    /// copies into a fresh allocation and zeroizes the old one before it is freed.
+    /// Without `zeroize` it is a plain `Vec::reserve`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut buf = Dynamic::<Vec<u8>>::new(vec![1u8; 4]);
+    /// buf.reserve(100);
+    /// assert!(buf.expose_secret().capacity() >= 104);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        secure_reserve(&mut self.0, additional, false);
+    }
+
+    /// Reserve capacity for exactly `additional` more bytes, without amortized slack.
+    ///
+    /// Less spare capacity means less room for stale secret bytes to linger after a
+    /// later shrink. Reallocation wipes the old buffer with `zeroize`, as in
+    /// [`reserve`](Self::reserve).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut buf = Dynamic::<Vec<u8>>::new(vec![1u8; 4]);
+    /// buf.reserve_exact(12);
+    /// assert!(buf.expose_secret().capacity() >= 16);
+    /// ```
+    pub fn reserve_exact(&mut self, additional: usize) {
+        secure_reserve(&mut self.0, additional, true);
+    }
+
+    /// Fallible [`reserve`](Self::reserve): reports allocation failure or capacity
+    /// overflow instead of aborting.
+    ///
+    /// Use this when `additional` is derived from untrusted input (e.g. a decoded
+    /// length prefix). On error the secret is left unchanged. Reallocation wipes the
+    /// old buffer with `zeroize`, as in [`reserve`](Self::reserve).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut buf = Dynamic::<Vec<u8>>::new(vec![1u8; 4]);
+    /// buf.try_reserve(100).unwrap();
+    /// assert!(buf.expose_secret().capacity() >= 104);
+    /// assert!(buf.try_reserve(usize::MAX).is_err());
+    /// assert_eq!(buf.expose_secret(), &[1u8; 4]);
+    /// ```
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        try_secure_reserve(&mut self.0, additional, false)
+    }
+
+    /// Fallible [`reserve_exact`](Self::reserve_exact); see [`try_reserve`](Self::try_reserve).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut buf = Dynamic::<Vec<u8>>::new(vec![1u8; 4]);
+    /// buf.try_reserve_exact(12).unwrap();
+    /// assert!(buf.expose_secret().capacity() >= 16);
+    /// ```
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        try_secure_reserve(&mut self.0, additional, true)
+    }
+}
+
+/// Concatenate secret fragments, in order, into one new secret.
+///
+/// The total length is computed up front, so the result is built with a single
+/// allocation and no intermediate growth. Inputs are read in place and left
+/// untouched. Handy for assembling transcripts or composite keys.
+///
+/// # Example
+///
+/// ```
+/// use secure_gate::{concat_secrets, Dynamic};
+/// let salt = Dynamic::<Vec<u8>>::new(vec![1, 2]);
+/// let ikm = Dynamic::<Vec<u8>>::new(vec![3, 4, 5]);
+/// let joined = concat_secrets(&[&salt, &ikm]);
+/// assert_eq!(joined.expose_secret(), &[1, 2, 3, 4, 5]);
+/// ```
+#[must_use = "dropping a derived secret — did you mean to use it?"]
+pub fn concat_secrets(parts: &[&Dynamic<Vec<u8>>]) -> Dynamic<Vec<u8>> {
+    let total = parts.iter().map(|p| p.0.len()).sum();
+    let mut out = Vec::with_capacity(total);
+    for part in parts {
+        out.extend_from_slice(&part.0);
+    }
+    Dynamic::new_boxed(Box::new(out))
+}
+
+// Make room for `additional` more bytes (`exact` skips amortized over-allocation).
+// With `zeroize`, growth goes through `wiping_reserve`.
+#[inline]
+fn secure_reserve(v: &mut Vec<u8>, additional: usize, exact: bool) {
+    #[cfg(feature = "zeroize")]
+    wiping_reserve(v, additional, exact);
+    #[cfg(not(feature = "zeroize"))]
+    {
+        if exact {
+            v.reserve_exact(additional);
+        } else {
+            v.reserve(additional);
+        }
+    }
+}
+
+// Make room for `additional` more elements by copying into a fresh allocation and
+// wiping the old one, rather than letting the allocator free it unwiped. The growth
+// policy shared by every secret buffer in the crate.
+#[cfg(feature = "zeroize")]
+pub(crate) fn wiping_reserve<T: Copy + zeroize::Zeroize>(
+    v: &mut Vec<T>,
+    additional: usize,
+    exact: bool,
+) {
+    if v.capacity() - v.len() >= additional {
+        return;
+    }
+    let needed = v.len().checked_add(additional).expect("capacity overflow");
+    let new_cap = if exact {
+        needed
+    } else {
+        needed.max(v.capacity() * 2)
+    };
+    let mut grown = Vec::with_capacity(new_cap);
+    grown.extend_from_slice(v);
+    let mut old = core::mem::replace(v, grown);
+    zeroize::Zeroize::zeroize(&mut old);
+}
+
+// Fallible `secure_reserve`: `v` is untouched on error. An overflowing `len + additional`
+// saturates to `usize::MAX`, which the allocator rejects as a capacity overflow.
+#[cfg(feature = "zeroize")]
+fn try_secure_reserve(
+    v: &mut Vec<u8>,
+    additional: usize,
+    exact: bool,
+) -> Result<(), TryReserveError> {
+    if v.capacity() - v.len() >= additional {
+        return Ok(());
+    }
+    let needed = v.len().saturating_add(additional);
+    let new_cap = if exact {
+        needed
+    } else {
+        needed.max(v.capacity() * 2)
+    };
+    let mut grown = Vec::new();
+    grown.try_reserve_exact(new_cap)?;
+    grown.extend_from_slice(v);
+    let mut old = core::mem::replace(v, grown);
+    zeroize::Zeroize::zeroize(&mut old);
+    Ok(())
+}
+
+#[cfg(not(feature = "zeroize"))]
+fn try_secure_reserve(
+    v: &mut Vec<u8>,
+    additional: usize,
+    exact: bool,
+) -> Result<(), TryReserveError> {
+    if exact {
+        v.try_reserve_exact(additional)
+    } else {
+        v.try_reserve(additional)
+    }
+}
+
+// === Convenient From impls ===
+impl<T> From<T> for Dynamic<T> {
+    #[inline(always)]
+    fn from(value: T) -> Self {
+        Self::new_boxed(Box::new(value))
+    }
+}
+
+impl<T: ?Sized> From<Box<T>> for Dynamic<T> {
+    #[inline(always)]
+    fn from(boxed: Box<T>) -> Self {
+        Self::new_boxed(boxed)
+    }
+}
+
+impl From<&str> for Dynamic<String> {
+    #[inline(always)]
+    fn from(s: &str) -> Self {
+        Self::new_boxed(Box::new(s.to_string()))
+    }
+}
+
+// Formatting straight into the secret (used by `secure_format!`). Growth goes
+// through `secure_reserve_str`, so with `zeroize` outgrown buffers are wiped, not freed dirty.
+impl core::fmt::Write for Dynamic<String> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        secure_reserve_str(&mut self.0, s.len());
+        self.0.push_str(s);
+        Ok(())
+    }
+}
+
+// Constant-time equality — only available with `ct-eq` feature (implied by `conversions`)
+#[cfg(feature = "ct-eq")]
+impl<T> Dynamic<T>
+where
+    T: ?Sized + AsRef<[u8]>,
+{
+    #[inline]
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        subtle::ConstantTimeEq::ct_eq((*self.0).as_ref(), (*other.0).as_ref()).into()
+    }
+}
+
+#[cfg(feature = "ct-eq")]
+impl Dynamic<String> {
+    /// Constant-time comparison against a candidate `&str` — the shape of a password check.
+    ///
+    /// Runtime depends only on the candidate's length: every candidate byte is
+    /// compared against the stored secret (past its end, against its last byte), and
+    /// the length check is folded in at the end, so neither the stored length nor a
+    /// matching prefix is revealed through timing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "ct-eq")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let stored: Dynamic<String> = "hunter2".into();
+    /// assert!(stored.ct_eq_str("hunter2"));
+    /// assert!(!stored.ct_eq_str("hunter"));
+    /// assert!(!stored.ct_eq_str("hunter22"));
+    /// # }
+    /// ```
+    pub fn ct_eq_str(&self, candidate: &str) -> bool {
+        crate::ct_eq_bytes(candidate.as_bytes(), self.0.as_bytes())
+    }
+
+    /// One-shot token check: compare `candidate` in constant time, then wipe it.
+    ///
+    /// Taking the candidate by value makes forgetting to wipe it impossible — with
+    /// `zeroize` it is wiped before this returns, whatever the outcome. Timing
+    /// behaves as in [`ct_eq_str`](Self::ct_eq_str).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "ct-eq")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let stored: Dynamic<String> = "reset-token".into();
+    /// assert!(stored.verify_candidate("reset-token".into()));
+    /// assert!(!stored.verify_candidate("guess".into()));
+    /// # }
+    /// ```
+    #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+    pub fn verify_candidate(&self, mut candidate: Dynamic<String>) -> bool {
+        let ok = crate::ct_eq_bytes(candidate.0.as_bytes(), self.0.as_bytes());
+        #[cfg(feature = "zeroize")]
+        candidate.zeroize_now();
+        ok
+    }
+}
+
+#[cfg(feature = "ct-eq")]
+impl Dynamic<Vec<u8>> {
+    /// Byte-string counterpart of `Dynamic<String>::verify_candidate`: compare in
+    /// constant time, then wipe the consumed candidate (with `zeroize`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "ct-eq")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let stored = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    /// assert!(stored.verify_candidate(Dynamic::new(vec![1, 2, 3])));
+    /// assert!(!stored.verify_candidate(Dynamic::new(vec![1, 2])));
+    /// # }
+    /// ```
+    #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+    pub fn verify_candidate(&self, mut candidate: Dynamic<Vec<u8>>) -> bool {
+        let ok = crate::ct_eq_bytes(&candidate.0, &self.0);
+        #[cfg(feature = "zeroize")]
+        candidate.zeroize_now();
+        ok
+    }
+}
+
+// Encoding / decoding — only available with `conversions` feature
+#[cfg(feature = "conversions")]
+impl Dynamic<Vec<u8>> {
+    /// Encode the secret as lowercase hex, keeping the encoding wrapped.
+    ///
+    /// Hex-encoded key material is as sensitive as the raw bytes, but
+    /// `expose_secret().to_hex()` hands back a bare `String`. This returns a
+    /// `Dynamic<String>` instead: redacted in `Debug` and wiped on drop (with `zeroize`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "conversions")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let key = Dynamic::<Vec<u8>>::new(vec![0xde, 0xad, 0xbe, 0xef]);
+    /// let hex = key.to_hex_secret();
+    /// assert_eq!(hex.expose_secret(), "deadbeef");
+    /// assert_eq!(format!("{hex:?}"), "[REDACTED]");
+    /// # }
+    /// ```
+    #[must_use = "dropping a converted secret — did you mean to use it?"]
+    pub fn to_hex_secret(&self) -> Dynamic<String> {
+        use crate::SecureConversionsExt;
+        Dynamic::new(self.0.as_slice().to_hex())
+    }
+
+    /// Encode the secret as URL-safe base64 (no padding), keeping the encoding wrapped.
+    ///
+    /// See [`to_hex_secret`](Self::to_hex_secret).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "conversions")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let key = Dynamic::<Vec<u8>>::new(vec![0xde, 0xad, 0xbe, 0xef]);
+    /// assert_eq!(key.to_base64url_secret().expose_secret(), "3q2-7w");
+    /// # }
+    /// ```
+    #[must_use = "dropping a converted secret — did you mean to use it?"]
+    pub fn to_base64url_secret(&self) -> Dynamic<String> {
+        use crate::SecureConversionsExt;
+        Dynamic::new(self.0.as_slice().to_base64url())
+    }
+
+    /// Create a `Dynamic` secret from a hex string.
+    ///
+    /// Decodes straight into the buffer that becomes the secret — no intermediate
+    /// copy. On error, any partially decoded bytes are wiped (with `zeroize`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "conversions")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let key = Dynamic::<Vec<u8>>::from_hex("DEADbeef")?;
+    /// assert_eq!(key.expose_secret(), &[0xde, 0xad, 0xbe, 0xef]);
+    /// # }
+    /// # Ok::<(), secure_gate::SecureGateError>(())
+    /// ```
+    pub fn from_hex(hex: &str) -> Result<Self, crate::SecureGateError> {
+        let mut bytes = alloc::vec![0u8; hex.len() / 2];
+        if hex::decode_to_slice(hex, &mut bytes).is_err() {
+            #[cfg(feature = "zeroize")]
+            zeroize::Zeroize::zeroize(&mut bytes);
+            return Err(crate::SecureGateError::InvalidHex);
+        }
+        Ok(Self::new_boxed(Box::new(bytes)))
+    }
+
+    /// Create a `Dynamic` secret from a base64url string (no padding).
+    ///
+    /// Decodes straight into the buffer that becomes the secret — no intermediate
+    /// copy. On error, any partially decoded bytes are wiped (with `zeroize`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "conversions")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let key = Dynamic::<Vec<u8>>::from_base64url("3q2-7w")?;
+    /// assert_eq!(key.expose_secret(), &[0xde, 0xad, 0xbe, 0xef]);
+    /// # }
+    /// # Ok::<(), secure_gate::SecureGateError>(())
+    /// ```
+    pub fn from_base64url(b64: &str) -> Result<Self, crate::SecureGateError> {
+        use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+        use base64::Engine;
+
+        let mut bytes = Vec::with_capacity(b64.len() * 3 / 4 + 3);
+        if URL_SAFE_NO_PAD.decode_vec(b64, &mut bytes).is_err() {
+            #[cfg(feature = "zeroize")]
+            zeroize::Zeroize::zeroize(&mut bytes);
+            return Err(crate::SecureGateError::InvalidBase64);
+        }
+        Ok(Self::new_boxed(Box::new(bytes)))
+    }
+}
+
+// Bounded bincode decoding — only available with `bincode` feature
+#[cfg(feature = "bincode")]
+impl Dynamic<Vec<u8>> {
+    /// Decode a bincode-encoded byte vector of at most `max_len` bytes.
+    ///
+    /// Expects the standard `bincode::serialize` layout (little-endian `u64` length
+    /// prefix, then the bytes) with no trailing data. The declared length is checked
+    /// against `max_len` **before** anything is allocated, and the decoder runs under
+    /// a matching size limit, so a hostile length prefix cannot force a large
+    /// allocation. This is the bounded path for secret blobs from semi-trusted
+    /// sources; `Dynamic<T>` itself still has no `Deserialize` impl.
+    ///
+    /// # Errors
+    ///
+    /// - [`CapacityExceeded`](crate::SecureGateError::CapacityExceeded) if the declared
+    ///   length exceeds `max_len`.
+    /// - [`InvalidBincode`](crate::SecureGateError::InvalidBincode) if the input is
+    ///   truncated, malformed, or has trailing bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "bincode")]
+    /// # {
+    /// use secure_gate::{Dynamic, SecureGateError};
+    /// let wire = bincode::serialize(&vec![1u8, 2, 3]).unwrap();
+    /// let blob = Dynamic::<Vec<u8>>::from_bincode_limited(&wire, 16)?;
+    /// assert_eq!(blob.expose_secret(), &[1, 2, 3]);
+    ///
+    /// let err = Dynamic::<Vec<u8>>::from_bincode_limited(&wire, 2).unwrap_err();
+    /// assert_eq!(err, SecureGateError::CapacityExceeded { capacity: 2, got: 3 });
+    /// # }
+    /// # Ok::<(), secure_gate::SecureGateError>(())
+    /// ```
+    pub fn from_bincode_limited(
+        bytes: &[u8],
+        max_len: usize,
+    ) -> Result<Self, crate::SecureGateError> {
+        use bincode::Options;
+
+        let prefix: [u8; 8] = bytes
+            .get(..8)
+            .and_then(|p| p.try_into().ok())
+            .ok_or(crate::SecureGateError::InvalidBincode)?;
+        let declared = u64::from_le_bytes(prefix);
+        if declared > max_len as u64 {
+            return Err(crate::SecureGateError::CapacityExceeded {
+                capacity: max_len,
+                got: usize::try_from(declared).unwrap_or(usize::MAX),
+            });
+        }
+
+        // Decode as a slice borrowed from `bytes` (the same wire layout as `Vec<u8>`),
+        // so a failure part-way through leaves no unwiped partial copy behind; the
+        // secret is copied out only once the whole input has been accepted.
+        let decoded: &[u8] = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .with_limit(declared.saturating_add(8))
+            .deserialize(bytes)
+            .map_err(|_| crate::SecureGateError::InvalidBincode)?;
+        Ok(Self::new_boxed(Box::new(decoded.to_vec())))
+    }
+}
+
+// Self-wiping scratch views — only available with `zeroize` feature
+#[cfg(feature = "zeroize")]
+impl Dynamic<Vec<u8>> {
+    /// Expose the bytes through a [`ScratchExpose`](crate::ScratchExpose) guard
+    /// that zeroizes them when it goes out of scope.
+    ///
+    /// Unlike [`expose_mut`](Self::expose_mut), the live buffer itself is wiped at
+    /// the end of the borrow — not only when the `Dynamic` is finally dropped. The
+    /// length is unchanged. Counts as one exposure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "zeroize")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let mut work = Dynamic::<Vec<u8>>::new(vec![0u8; 32]);
+    /// let sum: u32 = {
+    ///     let mut scratch = work.expose_scratch();
+    ///     scratch.copy_from_slice(&[7u8; 32]);
+    ///     scratch.iter().map(|&b| b as u32).sum()
+    /// };
+    /// assert_eq!(sum, 7 * 32);
+    /// assert!(work.expose_secret().iter().all(|&b| b == 0));
+    /// # }
+    /// ```
+    #[inline(always)]
+    #[cfg_attr(feature = "tracing", track_caller)]
+    pub fn expose_scratch(&mut self) -> crate::ScratchExpose<'_> {
+        crate::ScratchExpose(crate::scratch::WipeOnDrop(
+            self.expose_secret_mut().as_mut_slice(),
+        ))
+    }
+}
+
+// Streaming reads — only available with `std` feature
+#[cfg(feature = "std")]
+impl Dynamic<Vec<u8>> {
+    /// Stream the bytes through a [`SecretReader`](crate::SecretReader), an
+    /// `std::io::Read` adapter that borrows the secret.
+    ///
+    /// For consumers that take `impl Read`, such as `std::io::copy` into a hasher.
+    /// The caller never receives the full slice, and the exposure lasts only as
+    /// long as the reader. Counts as one exposure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// use std::io::Read;
+    /// let key = Dynamic::<Vec<u8>>::new(vec![1, 2, 3, 4, 5]);
+    /// let mut reader = key.reader();
+    /// let mut head = [0u8; 2];
+    /// reader.read_exact(&mut head).unwrap();
+    /// assert_eq!(head, [1, 2]);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn reader(&self) -> crate::SecretReader<'_> {
+        crate::SecretReader(self.expose_secret().as_slice())
+    }
+}
+
+// Random generation — only available with `rand` feature
+#[cfg(feature = "rand")]
+impl Dynamic<Vec<u8>> {
+    /// Generate fresh random bytes of the specified length using the OS RNG.
+    ///
+    /// This is a convenience method that generates random bytes directly
+    /// without going through `DynamicRng`. Equivalent to:
+    /// `DynamicRng::generate(len).into_inner()`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let random: Dynamic<Vec<u8>> = Dynamic::generate_random(64);
+    /// assert_eq!(random.len(), 64);
+    /// # }
+    /// ```
+    #[must_use = "dropping a freshly generated secret — did you mean to use it?"]
+    #[inline]
+    pub fn generate_random(len: usize) -> Self {
+        crate::rng::DynamicRng::generate(len).into_inner()
+    }
+
+    /// Overwrite every byte in place with fresh OS randomness, keeping the length.
+    ///
+    /// Reuses the existing allocation, so a rotation loop neither reallocates nor
+    /// leaves an old buffer behind to be wiped separately.
+    ///
+    /// Panics if the RNG fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let mut key: Dynamic<Vec<u8>> = Dynamic::new(vec![0u8; 64]);
+    /// key.fill_random();
+    /// assert_eq!(key.len(), 64);
+    /// assert!(key.expose_secret().iter().any(|&b| b != 0));
+    /// # }
+    /// ```
+    pub fn fill_random(&mut self) {
+        crate::rng::fill_os_random(&mut self.0);
+    }
+
+    /// Randomly permute the bytes in place with an unbiased Fisher–Yates shuffle.
+    ///
+    /// Swap indices come from [`DynamicRng::gen_range_usize`](crate::rng::DynamicRng::gen_range_usize),
+    /// so every permutation is equally likely and the bytes never leave the wrapper.
+    ///
+    /// Panics if the RNG fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let mut deck: Dynamic<Vec<u8>> = Dynamic::new((0..52).collect::<Vec<u8>>());
+    /// deck.shuffle();
+    /// let mut sorted = deck.expose_secret().clone();
+    /// sorted.sort_unstable();
+    /// assert_eq!(sorted, (0..52).collect::<Vec<u8>>());
+    /// # }
+    /// ```
+    pub fn shuffle(&mut self) {
+        for i in (1..self.0.len()).rev() {
+            let j = crate::rng::DynamicRng::gen_range_usize(0, i + 1);
+            self.0.swap(i, *j.expose_secret());
+        }
+    }
+}
+
+// Exposure counting — only available with `count-exposures` feature
+#[cfg(feature = "count-exposures")]
+impl<T: ?Sized> Dynamic<T> {
+    /// Number of times this secret has been exposed through `expose()`,
+    /// `expose_mut()` or `expose_secret_mut()`.
+    ///
+    /// A debugging aid for spotting over-exposure in hot loops. `expose_secret()`
+    /// stays a `const fn` and is not counted. Counts live in a side table keyed by
+    /// the heap allocation, so the wrapper layout is unchanged and the count
+    /// follows the secret across moves; compare two readings rather than relying
+    /// on an absolute value. Counting only happens in debug builds; in release
+    /// builds this always returns `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "count-exposures")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let mut pw: Dynamic<String> = "hunter2".into();
+    /// let before = pw.exposure_count();
+    /// let _ = pw.expose();
+    /// pw.expose_secret_mut().push('!');
+    /// # #[cfg(debug_assertions)]
+    /// assert_eq!(pw.exposure_count() - before, 2);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn exposure_count(&self) -> usize {
+        crate::exposure_count::count(&*self.0)
+    }
+}
+
+// Zeroize integration
+#[cfg(feature = "zeroize")]
+impl<T: ?Sized + zeroize::Zeroize> zeroize::Zeroize for Dynamic<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: ?Sized + zeroize::Zeroize> zeroize::ZeroizeOnDrop for Dynamic<T> {}
+
+// Serde integration — only available with `serde` feature
+#[cfg(feature = "serde")]
+impl<T: ?Sized + serde::Serialize> serde::Serialize for Dynamic<T> {
+    /// Serializes the inner value transparently.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+// No `Deserialize` for `Dynamic<T>`: heap secrets of arbitrary length should not be
+// materialized straight from untrusted input.
+
+// `secrecy` interop — only available with `secrecy-compat` feature
+#[cfg(feature = "secrecy-compat")]
+impl<T: ?Sized + zeroize::Zeroize> From<Dynamic<T>> for secrecy::SecretBox<T> {
+    /// Moves the existing heap allocation into a `SecretBox` — the secret is neither
+    /// copied nor exposed on the way.
+    #[inline]
+    fn from(value: Dynamic<T>) -> Self {
+        secrecy::SecretBox::new(value.0)
+    }
+}
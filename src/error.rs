@@ -0,0 +1,74 @@
+// ==========================================================================
+// src/error.rs
+// ==========================================================================
+
+use core::fmt;
+
+/// Error returned by the crate's fallible constructors and operations.
+///
+/// Matchable by failure mode, and its `Display` output is a short lowercase
+/// message suitable for logs (it never contains secret data).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "conversions")]
+/// # {
+/// use secure_gate::{Fixed, SecureGateError};
+/// let err = Fixed::<[u8; 4]>::from_hex("dead").unwrap_err();
+/// assert_eq!(err, SecureGateError::LengthMismatch { expected: 4, got: 2 });
+/// assert_eq!(err.to_string(), "length mismatch: expected 4 bytes, got 2");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SecureGateError {
+    /// Input was not valid hexadecimal (bad digit or odd length).
+    InvalidHex,
+    /// Input was not valid URL-safe base64 (no padding).
+    InvalidBase64,
+    /// Decoded or supplied data had the wrong length.
+    LengthMismatch { expected: usize, got: usize },
+    /// Data does not fit in a fixed-capacity buffer.
+    CapacityExceeded { capacity: usize, got: usize },
+    /// Length is not a multiple of the requested chunk size.
+    ChunkMisaligned { chunk_size: usize, len: usize },
+    /// A position or amount exceeded the secret's length.
+    OutOfBounds { index: usize, len: usize },
+    /// The OS random number generator failed.
+    RngFailure,
+    /// Bytes were not valid UTF-8.
+    InvalidUtf8,
+    /// Input was not a valid bincode encoding (truncated, malformed, or trailing data).
+    InvalidBincode,
+}
+
+impl fmt::Display for SecureGateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidHex => f.write_str("invalid hex string"),
+            Self::InvalidBase64 => f.write_str("invalid base64url string"),
+            Self::LengthMismatch { expected, got } => {
+                write!(f, "length mismatch: expected {expected} bytes, got {got}")
+            }
+            Self::CapacityExceeded { capacity, got } => {
+                write!(f, "capacity exceeded: {got} bytes do not fit in {capacity}")
+            }
+            Self::ChunkMisaligned { chunk_size, len } => {
+                write!(
+                    f,
+                    "length {len} is not a multiple of the chunk size {chunk_size}"
+                )
+            }
+            Self::OutOfBounds { index, len } => {
+                write!(f, "index {index} is out of bounds for length {len}")
+            }
+            Self::RngFailure => f.write_str("random number generator failure"),
+            Self::InvalidUtf8 => f.write_str("invalid UTF-8"),
+            Self::InvalidBincode => f.write_str("invalid bincode data"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SecureGateError {}
@@ -0,0 +1,216 @@
+// ==========================================================================
+// src/expose.rs
+// ==========================================================================
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Read-only, typed view of an exposed secret, returned by `expose()`.
+///
+/// A view is as explicit as `expose_secret()` — taking one counts as an exposure —
+/// but offers the same accessors whether the secret lives on the stack or the
+/// heap, e.g. `.expose().as_slice()` for `Fixed<[u8; N]>` and `Dynamic<Vec<u8>>`.
+/// `Debug` is redacted; there is no `Deref`.
+///
+/// # Example
+///
+/// ```
+/// use secure_gate::{Dynamic, Fixed};
+/// let key = Fixed::new([1u8, 2, 3]);
+/// let blob = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+/// assert_eq!(key.expose().as_slice(), blob.expose().as_slice());
+/// assert_eq!(key.expose().as_array(), &[1, 2, 3]);
+/// ```
+pub struct Expose<'a, T: ?Sized>(pub(crate) &'a T);
+
+/// Mutable, typed view of an exposed secret, returned by `expose_mut()`.
+///
+/// # Example
+///
+/// ```
+/// use secure_gate::Fixed;
+/// let mut key = Fixed::new([0u8; 4]);
+/// key.expose_mut().as_mut_slice().copy_from_slice(&[9, 9, 9, 9]);
+/// assert_eq!(key.expose_secret(), &[9, 9, 9, 9]);
+/// ```
+pub struct ExposeMut<'a, T: ?Sized>(pub(crate) &'a mut T);
+
+/// Mutable scratch view of a secret's bytes that wipes them when dropped,
+/// returned by `Dynamic::<Vec<u8>>::expose_scratch()`.
+///
+/// Derefs to `[u8]` for reading and writing. On drop — including during a panic
+/// unwind — the viewed bytes are zeroized in place: the secret keeps its length
+/// but holds only zeros afterwards. Use it for truly ephemeral buffers whose live
+/// contents should not outlast the scope. `Debug` is redacted.
+///
+/// Requires the `zeroize` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "zeroize")]
+/// # {
+/// use secure_gate::Dynamic;
+/// let mut buf = Dynamic::<Vec<u8>>::new(vec![0u8; 16]);
+/// {
+///     let mut scratch = buf.expose_scratch();
+///     scratch.fill(0x5A);
+///     assert_eq!(scratch[0], 0x5A);
+/// }
+/// assert_eq!(buf.expose_secret(), &[0u8; 16]);
+/// # }
+/// ```
+#[cfg(feature = "zeroize")]
+pub struct ScratchExpose<'a>(pub(crate) crate::scratch::WipeOnDrop<'a, [u8]>);
+
+/// Streaming view of a secret's bytes implementing `std::io::Read`, returned by
+/// `Dynamic::<Vec<u8>>::reader()`.
+///
+/// Feeds the secret into APIs that take `impl Read` (e.g. `std::io::copy` into a
+/// hasher) without handing the whole slice to the caller. The reader borrows the
+/// secret, so the exposure ends when it is dropped. `Debug` is redacted.
+///
+/// Requires the `std` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "std")]
+/// # {
+/// use secure_gate::Dynamic;
+/// let key = Dynamic::<Vec<u8>>::new(vec![7u8; 64]);
+/// let mut sink = Vec::new();
+/// let copied = std::io::copy(&mut key.reader(), &mut sink).unwrap();
+/// assert_eq!(copied, 64);
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub struct SecretReader<'a>(pub(crate) &'a [u8]);
+
+impl<'a, const N: usize> Expose<'a, [u8; N]> {
+    /// The bytes as a slice.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &'a [u8] {
+        self.0
+    }
+
+    /// The bytes as a fixed-size array reference.
+    #[inline(always)]
+    pub fn as_array(&self) -> &'a [u8; N] {
+        self.0
+    }
+}
+
+impl<'a, const N: usize> ExposeMut<'a, [u8; N]> {
+    /// The bytes as a mutable slice.
+    #[inline(always)]
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        self.0
+    }
+
+    /// The bytes as a mutable fixed-size array reference.
+    #[inline(always)]
+    pub fn as_mut_array(&mut self) -> &mut [u8; N] {
+        self.0
+    }
+}
+
+impl<'a> Expose<'a, Vec<u8>> {
+    /// The bytes as a slice.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &'a [u8] {
+        self.0
+    }
+}
+
+impl<'a> ExposeMut<'a, Vec<u8>> {
+    /// The bytes as a mutable slice (length cannot change through the view).
+    #[inline(always)]
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        self.0
+    }
+}
+
+impl<'a> Expose<'a, String> {
+    /// The string as `&str`.
+    #[inline(always)]
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// The UTF-8 bytes as a slice.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &'a [u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for Expose<'_, [u8; N]> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[u8] {
+        self.0
+    }
+}
+
+impl AsRef<[u8]> for Expose<'_, Vec<u8>> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[u8] {
+        self.0
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl core::ops::Deref for ScratchExpose<'_> {
+    type Target = [u8];
+
+    #[inline(always)]
+    fn deref(&self) -> &[u8] {
+        self.0 .0
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl core::ops::DerefMut for ScratchExpose<'_> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.0 .0
+    }
+}
+
+// No `Drop` impl of its own: the inner `WipeOnDrop` zeroizes the view
+
+// Reading advances the borrowed slice, so it doubles as the cursor
+#[cfg(feature = "std")]
+impl std::io::Read for SecretReader<'_> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.0.read(buf)
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for Expose<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for ExposeMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl fmt::Debug for ScratchExpose<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Debug for SecretReader<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
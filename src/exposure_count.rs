@@ -0,0 +1,103 @@
+// ==========================================================================
+// src/exposure_count.rs
+// ==========================================================================
+
+// Exposure counts behind the `count-exposures` feature.
+//
+// In debug builds with the feature on, `expose_secret_mut()` and the typed
+// `expose()` / `expose_mut()` views record each access in a fixed-size side
+// table keyed by the secret's address. Nothing is stored in the wrappers, so
+// they keep their one-field layout and `expose_secret()` stays a `const fn` in
+// every configuration. Otherwise `record` is a no-op and `count` reports `0`.
+//
+// Keys are addresses, so a count follows the memory rather than the value:
+// heap wrappers are keyed by their allocation and keep counting across moves,
+// while a by-value wrapper that is moved starts again at its new address.
+// Entries are not cleared on drop, and the least recently claimed slot in a
+// full probe window is reused.
+
+#[cfg(all(feature = "count-exposures", debug_assertions))]
+mod table {
+    use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering::Relaxed};
+
+    const SLOTS: usize = 1024;
+    const PROBES: usize = 16;
+
+    // Address `0` marks an empty slot. The lock makes each read-modify-write of a
+    // slot atomic as a whole; the atomics only avoid `unsafe` interior mutability.
+    struct Slot {
+        addr: AtomicUsize,
+        count: AtomicUsize,
+    }
+
+    #[allow(clippy::declare_interior_mutable_const)]
+    const EMPTY: Slot = Slot {
+        addr: AtomicUsize::new(0),
+        count: AtomicUsize::new(0),
+    };
+
+    static TABLE: [Slot; SLOTS] = [EMPTY; SLOTS];
+    static LOCK: AtomicBool = AtomicBool::new(false);
+
+    fn locked<R>(f: impl FnOnce() -> R) -> R {
+        while LOCK
+            .compare_exchange_weak(false, true, core::sync::atomic::Ordering::Acquire, Relaxed)
+            .is_err()
+        {
+            core::hint::spin_loop();
+        }
+        let result = f();
+        LOCK.store(false, core::sync::atomic::Ordering::Release);
+        result
+    }
+
+    fn window(addr: usize) -> impl Iterator<Item = &'static Slot> {
+        let start = (addr >> 3).wrapping_mul(0x9E37_79B9) % SLOTS;
+        (0..PROBES).map(move |i| &TABLE[(start + i) % SLOTS])
+    }
+
+    pub(crate) fn record(addr: usize) {
+        locked(|| {
+            let slot = window(addr)
+                .find(|s| matches!(s.addr.load(Relaxed), a if a == addr || a == 0))
+                .unwrap_or_else(|| window(addr).next().expect("PROBES > 0"));
+            if slot.addr.load(Relaxed) != addr {
+                slot.addr.store(addr, Relaxed);
+                slot.count.store(0, Relaxed);
+            }
+            slot.count.fetch_add(1, Relaxed);
+        });
+    }
+
+    pub(crate) fn count(addr: usize) -> usize {
+        locked(|| {
+            window(addr)
+                .find(|s| s.addr.load(Relaxed) == addr)
+                .map_or(0, |s| s.count.load(Relaxed))
+        })
+    }
+}
+
+// Record one exposure of the secret at `value`'s address.
+#[cfg(all(feature = "count-exposures", debug_assertions))]
+#[inline(always)]
+pub(crate) fn record<T: ?Sized>(value: &T) {
+    table::record(value as *const T as *const () as usize);
+}
+
+#[cfg(not(all(feature = "count-exposures", debug_assertions)))]
+#[inline(always)]
+pub(crate) fn record<T: ?Sized>(_value: &T) {}
+
+// Exposures recorded so far at `value`'s address.
+#[cfg(all(feature = "count-exposures", debug_assertions))]
+#[inline(always)]
+pub(crate) fn count<T: ?Sized>(value: &T) -> usize {
+    table::count(value as *const T as *const () as usize)
+}
+
+#[cfg(all(feature = "count-exposures", not(debug_assertions)))]
+#[inline(always)]
+pub(crate) fn count<T: ?Sized>(_value: &T) -> usize {
+    0
+}
@@ -0,0 +1,27 @@
+// ==========================================================================
+// src/exposure_trace.rs
+// ==========================================================================
+
+// Mutable-exposure events behind the `tracing` feature.
+//
+// Every `expose_secret_mut()` emits one `TRACE` event on the
+// `secure_gate::exposure` target carrying the wrapper name, the secret's type
+// name and handle size, and the caller's source location — never the value.
+// Without the feature none of this is compiled in.
+
+// `handle_size` is `size_of_val` of the wrapped value — the in-memory size of
+// the handle, not the secret's length: it equals the byte length for arrays,
+// slices and `str`, but is fixed (24 bytes on 64-bit) for `Vec` / `String`, so
+// heap contents never influence what is logged
+#[track_caller]
+#[inline(always)]
+pub(crate) fn mutable_exposure<T: ?Sized>(wrapper: &'static str, value: &T) {
+    tracing::trace!(
+        target: "secure_gate::exposure",
+        wrapper = wrapper,
+        type_name = core::any::type_name::<T>(),
+        handle_size = core::mem::size_of_val(value),
+        location = %core::panic::Location::caller(),
+        "mutable secret exposure"
+    );
+}
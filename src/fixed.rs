@@ -4,6 +4,12 @@
 
 use core::fmt;
 
+/// Largest byte size accepted for stack-allocated fixed secrets (1 MiB).
+///
+/// Enforced at compile time by [`fixed_alias!`](crate::fixed_alias), which also
+/// accepts a custom `max = ...` bound for tighter (e.g. embedded) limits.
+pub const MAX_FIXED_SIZE: usize = 1024 * 1024;
+
 /// Stack-allocated secure secret wrapper.
 ///
 /// This is a zero-cost wrapper for fixed-size secrets like byte arrays or primitives.
@@ -55,6 +61,7 @@ impl<T> Fixed<T> {
     /// use secure_gate::Fixed;
     /// const SECRET: Fixed<u32> = Fixed::new(42);
     /// ```
+    #[must_use = "dropping a newly wrapped secret — did you mean to bind it?"]
     #[inline(always)]
     pub const fn new(value: T) -> Self {
         Fixed(value)
@@ -89,10 +96,54 @@ impl<T> Fixed<T> {
     /// assert_eq!(secret.expose_secret()[0], 42);
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "tracing", track_caller)]
     pub fn expose_secret_mut(&mut self) -> &mut T {
+        crate::exposure_count::record(&self.0);
+        #[cfg(feature = "tracing")]
+        crate::exposure_trace::mutable_exposure("Fixed", &self.0);
         &mut self.0
     }
 
+    /// Expose the secret through a typed read-only [`Expose`](crate::Expose) view.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// let key = Fixed::new([7u8; 16]);
+    /// assert_eq!(key.expose().as_slice().len(), 16);
+    /// ```
+    #[inline(always)]
+    pub fn expose(&self) -> crate::Expose<'_, T> {
+        crate::exposure_count::record(&self.0);
+        crate::Expose(self.expose_secret())
+    }
+
+    /// Expose the secret through a typed mutable [`ExposeMut`](crate::ExposeMut) view.
+    #[inline(always)]
+    #[cfg_attr(feature = "tracing", track_caller)]
+    pub fn expose_mut(&mut self) -> crate::ExposeMut<'_, T> {
+        crate::ExposeMut(self.expose_secret_mut())
+    }
+
+    /// Replace the secret with a new value, returning the previous one still wrapped.
+    ///
+    /// Useful for key rotation: the old secret comes back as a `Fixed`, so it is
+    /// still redacted and (with `zeroize`) can be wiped like any other secret.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// let mut key = Fixed::new([1u8; 32]);
+    /// let old = key.replace([2u8; 32]);
+    /// assert_eq!(old.expose_secret(), &[1u8; 32]);
+    /// assert_eq!(key.expose_secret(), &[2u8; 32]);
+    /// ```
+    #[inline]
+    pub fn replace(&mut self, new: T) -> Fixed<T> {
+        Fixed::new(core::mem::replace(&mut self.0, new))
+    }
 
     /// Convert to a non-cloneable variant.
     ///
@@ -106,10 +157,65 @@ impl<T> Fixed<T> {
     /// let no_clone = secret.no_clone();
     /// // no_clone cannot be cloned
     /// ```
+    #[must_use = "dropping a derived secret — did you mean to use it?"]
     #[inline(always)]
     pub fn no_clone(self) -> crate::FixedNoClone<T> {
         crate::FixedNoClone::new(self.0)
     }
+
+    /// Duplicate the secret — identical to `.clone()`, but named for audits.
+    ///
+    /// Prefer this over `.clone()` so every intentional secret copy in a codebase
+    /// can be found with a single grep for `clone_secret`.
+    ///
+    /// # Failure during clone
+    ///
+    /// The new `Fixed` is only assembled after `T::clone` returns, so a panicking
+    /// clone never leaves a half-built wrapper behind and the source is untouched.
+    /// Partial state *inside* `T::clone` (e.g. the fields a derived `Clone` already
+    /// copied) is dropped by `T`'s own unwinding; make those fields self-wiping
+    /// (`Zeroizing`, or this crate's wrappers) if they must not linger. Allocation
+    /// failure while cloning a `Vec` aborts the process rather than unwinding.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// let key = Fixed::new([7u8; 32]);
+    /// let backup = key.clone_secret(); // intentional, grep-able duplication
+    /// assert_eq!(backup.expose_secret(), key.expose_secret());
+    /// ```
+    #[must_use = "dropping a derived secret — did you mean to use it?"]
+    #[inline(always)]
+    pub fn clone_secret(&self) -> Self
+    where
+        Self: Clone,
+    {
+        self.clone()
+    }
+
+    /// Copy the inner value out — the auditable form of `*secret.expose_secret()`.
+    ///
+    /// For `Copy` inner types the copy is unavoidable, and an implicit deref-and-copy
+    /// is easy to miss in review. Naming it makes every such copy grep-able as
+    /// `copy_out`. The returned value is a plain `T` and is **not** wiped on drop.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// let pin = Fixed::new(4242u32);
+    /// let raw: u32 = pin.copy_out(); // loud, auditable copy
+    /// assert_eq!(raw, 4242);
+    /// ```
+    #[must_use = "dropping a derived secret — did you mean to use it?"]
+    #[inline(always)]
+    pub fn copy_out(&self) -> T
+    where
+        T: Copy,
+    {
+        *self.expose_secret()
+    }
 }
 
 // Explicit zeroization — only available with `zeroize` feature
@@ -120,6 +226,9 @@ impl<T: zeroize::Zeroize> Fixed<T> {
     /// This is useful when you want to wipe memory before the value goes out of scope,
     /// or when you want to make the zeroization intent explicit in the code.
     ///
+    /// Works for composite secrets too: wrap a `#[derive(Zeroize)]` struct and
+    /// every field (arrays, `Vec`s, nested structs) is wiped.
+    ///
     /// # Example
     ///
     /// ```
@@ -140,6 +249,30 @@ impl<T: zeroize::Zeroize> Fixed<T> {
 // === Byte-array specific helpers ===
 
 impl<const N: usize> Fixed<[u8; N]> {
+    /// The fixed length in bytes, usable in const contexts without an instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// type Key = Fixed<[u8; 32]>;
+    /// let scratch = [0u8; Key::LEN];
+    /// assert_eq!(scratch.len(), 32);
+    /// ```
+    pub const LEN: usize = N;
+
+    /// `true` if `N == 0`; the const counterpart of [`is_empty`](Self::is_empty).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// const HAS_BYTES: bool = !Fixed::<[u8; 16]>::IS_EMPTY;
+    /// assert!(HAS_BYTES);
+    /// assert!(Fixed::<[u8; 0]>::IS_EMPTY);
+    /// ```
+    pub const IS_EMPTY: bool = N == 0;
+
     /// Returns the fixed length in bytes.
     ///
     /// This is safe public metadata — does not expose the secret.
@@ -168,6 +301,7 @@ impl<const N: usize> Fixed<[u8; N]> {
     /// let secret = Fixed::<[u8; 3]>::from_slice(bytes);
     /// assert_eq!(secret.expose_secret(), &[1, 2, 3]);
     /// ```
+    #[must_use = "dropping a newly wrapped secret — did you mean to bind it?"]
     #[inline]
     pub fn from_slice(bytes: &[u8]) -> Self {
         assert_eq!(bytes.len(), N, "slice length mismatch");
@@ -175,6 +309,135 @@ impl<const N: usize> Fixed<[u8; N]> {
         arr.copy_from_slice(&bytes[..N]);
         Self::new(arr)
     }
+
+    /// Read the byte at index `i`.
+    ///
+    /// An auditable single-byte accessor in place of `Index` (which would make every
+    /// `key[i]` an invisible exposure). Counts as an exposure.
+    ///
+    /// Panics if `i >= N`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// let key = Fixed::new([7u8, 8, 9]);
+    /// assert_eq!(key.get_byte(1), 8);
+    /// ```
+    #[inline]
+    pub fn get_byte(&self, i: usize) -> u8 {
+        self.expose_secret()[i]
+    }
+
+    /// Overwrite the byte at index `i` with `v`.
+    ///
+    /// The mutable counterpart of [`get_byte`](Self::get_byte), in place of `IndexMut`.
+    /// Counts as an exposure.
+    ///
+    /// Panics if `i >= N`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// let mut key = Fixed::new([0u8; 4]);
+    /// key.set_byte(0, 1);
+    /// assert_eq!(key.expose_secret(), &[1, 0, 0, 0]);
+    /// ```
+    #[inline]
+    pub fn set_byte(&mut self, i: usize, v: u8) {
+        self.expose_secret_mut()[i] = v;
+    }
+
+    /// Add 1 to the bytes read as a big-endian integer, wrapping to all zeros on
+    /// overflow — e.g. for per-message AEAD counter nonces.
+    ///
+    /// Not constant-time: the carry stops at the first byte that does not overflow.
+    /// That is fine for counters, whose values are not secret; do not use it on keys.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// let mut nonce = Fixed::new([0x00, 0x00, 0xFF]);
+    /// nonce.increment_be();
+    /// assert_eq!(nonce.expose_secret(), &[0x00, 0x01, 0x00]);
+    /// ```
+    #[inline]
+    pub fn increment_be(&mut self) {
+        increment(self.expose_secret_mut().iter_mut().rev());
+    }
+
+    /// Little-endian counterpart of [`increment_be`](Self::increment_be): the carry
+    /// runs from the first byte towards the last.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// let mut nonce = Fixed::new([0xFF, 0xFF, 0x00]);
+    /// nonce.increment_le();
+    /// assert_eq!(nonce.expose_secret(), &[0x00, 0x00, 0x01]);
+    /// ```
+    #[inline]
+    pub fn increment_le(&mut self) {
+        increment(self.expose_secret_mut().iter_mut());
+    }
+
+    /// One-line diagnostic summary: type and byte length, never content.
+    ///
+    /// An explicit opt-in for structured logs — richer than the `[REDACTED]` `Debug`
+    /// output, which is unchanged. The length is `N`, which is public metadata. The
+    /// type name comes from `core::any::type_name` (paths stripped), so treat it as
+    /// informational rather than a stable format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// let key = Fixed::new([0u8; 32]);
+    /// assert_eq!(key.redacted_summary(), "Fixed<[u8; 32]>(len=32)");
+    /// assert_eq!(format!("{key:?}"), "[REDACTED]");
+    /// ```
+    pub fn redacted_summary(&self) -> alloc::string::String {
+        crate::redact::redacted_summary::<[u8; N]>("Fixed", N)
+    }
+
+    /// A `Debug` stand-in that prints `[REDACTED len=N]` with the byte length `N`.
+    ///
+    /// For hand-written `Debug` impls of structs full of secrets. The wrapper's own
+    /// `Debug` stays `[REDACTED]`: lengths can be sensitive (e.g. a password's), so
+    /// they are only shown where a call site asks for them.
+    ///
+    /// Requires the `debug-len` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "debug-len")]
+    /// # {
+    /// use secure_gate::Fixed;
+    /// let key = Fixed::new([0u8; 32]);
+    /// assert_eq!(format!("{:?}", key.redacted_debug_with_len()), "[REDACTED len=32]");
+    /// # }
+    /// ```
+    #[cfg(feature = "debug-len")]
+    #[inline]
+    pub fn redacted_debug_with_len(&self) -> impl fmt::Debug {
+        crate::redact::RedactedLen(N)
+    }
+}
+
+// Add 1 with carry, visiting bytes from least to most significant; wraps on overflow
+#[inline]
+fn increment<'a>(bytes: impl Iterator<Item = &'a mut u8>) {
+    for b in bytes {
+        let (sum, carry) = b.overflowing_add(1);
+        *b = sum;
+        if !carry {
+            break;
+        }
+    }
 }
 
 impl<const N: usize> From<[u8; N]> for Fixed<[u8; N]> {
@@ -201,29 +464,30 @@ impl<T> fmt::Debug for Fixed<T> {
     }
 }
 
-// Explicit Clone only — no implicit Copy
+// Explicit Clone only — no implicit Copy. Nothing is held while `T::clone` runs,
+// so a panicking clone leaves no partially built wrapper (see `clone_secret`).
 impl<T: Clone> Clone for Fixed<T> {
     #[inline(always)]
     fn clone(&self) -> Self {
-        Self(self.0.clone())
+        Self::new(self.0.clone())
     }
 }
 
 // REMOVED: Copy impl for Fixed<[u8; N]>
 // Implicit copying of secrets is a footgun — duplication must be intentional.
 
-// Constant-time equality — only available with `conversions` feature
-#[cfg(feature = "conversions")]
+// Constant-time equality — only available with `ct-eq` feature (implied by `conversions`)
+#[cfg(feature = "ct-eq")]
 impl<const N: usize> Fixed<[u8; N]> {
     /// Constant-time equality comparison.
     ///
     /// This is the **only safe way** to compare two fixed-size secrets.
-    /// Available only when the `conversions` feature is enabled.
+    /// Available only when the `ct-eq` feature is enabled (implied by `conversions`).
     ///
     /// # Example
     ///
     /// ```
-    /// # #[cfg(feature = "conversions")]
+    /// # #[cfg(feature = "ct-eq")]
     /// # {
     /// use secure_gate::Fixed;
     /// let a = Fixed::new([1u8; 32]);
@@ -233,10 +497,37 @@ impl<const N: usize> Fixed<[u8; N]> {
     /// ```
     #[inline]
     pub fn ct_eq(&self, other: &Self) -> bool {
-        use crate::conversions::SecureConversionsExt;
-        self.expose_secret().ct_eq(other.expose_secret())
+        subtle::ConstantTimeEq::ct_eq(self.0.as_slice(), other.0.as_slice()).into()
+    }
+
+    /// Constant-time check for an all-zero value.
+    ///
+    /// ORs every byte together and compares the result once, so timing does not
+    /// depend on where (or whether) a non-zero byte appears. Use it to reject
+    /// all-zero keys or scalars instead of `== [0; N]`. An empty array is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "ct-eq")]
+    /// # {
+    /// use secure_gate::Fixed;
+    /// assert!(Fixed::new([0u8; 32]).is_zero_ct());
+    /// let mut key = [0u8; 32];
+    /// key[31] = 1;
+    /// assert!(!Fixed::new(key).is_zero_ct());
+    /// # }
+    /// ```
+    #[inline]
+    pub fn is_zero_ct(&self) -> bool {
+        let acc = self.0.iter().fold(0u8, |acc, &b| acc | b);
+        subtle::ConstantTimeEq::ct_eq(&acc, &0u8).into()
     }
+}
 
+// Decoding constructors — only available with `conversions` feature
+#[cfg(feature = "conversions")]
+impl<const N: usize> Fixed<[u8; N]> {
     /// Create a `Fixed` secret from a hex string.
     ///
     /// Returns `Err` if the hex string is invalid or doesn't match the expected length.
@@ -251,16 +542,17 @@ impl<const N: usize> Fixed<[u8; N]> {
     /// let key = Fixed::<[u8; 4]>::from_hex("deadbeef")?;
     /// assert_eq!(key.expose_secret(), &[0xde, 0xad, 0xbe, 0xef]);
     /// # }
-    /// # Ok::<(), &'static str>(())
+    /// # Ok::<(), secure_gate::SecureGateError>(())
     /// ```
-    pub fn from_hex(hex: &str) -> Result<Self, &'static str> {
+    pub fn from_hex(hex: &str) -> Result<Self, crate::SecureGateError> {
         let mut bytes = hex::decode(hex)
-            .map_err(|_| "invalid hex string")?;
+            .map_err(|_| crate::SecureGateError::InvalidHex)?;
         
         if bytes.len() != N {
+            let got = bytes.len();
             #[cfg(feature = "zeroize")]
             zeroize::Zeroize::zeroize(&mut bytes);
-            return Err("hex string length mismatch");
+            return Err(crate::SecureGateError::LengthMismatch { expected: N, got });
         }
         
         let mut arr = [0u8; N];
@@ -287,19 +579,20 @@ impl<const N: usize> Fixed<[u8; N]> {
     /// let key = Fixed::<[u8; 4]>::from_base64url(&b64)?;
     /// assert_eq!(key.expose_secret(), &[0xde, 0xad, 0xbe, 0xef]);
     /// # }
-    /// # Ok::<(), &'static str>(())
+    /// # Ok::<(), secure_gate::SecureGateError>(())
     /// ```
-    pub fn from_base64url(b64: &str) -> Result<Self, &'static str> {
+    pub fn from_base64url(b64: &str) -> Result<Self, crate::SecureGateError> {
         use base64::engine::general_purpose::URL_SAFE_NO_PAD;
         use base64::Engine;
         
         let mut bytes = URL_SAFE_NO_PAD.decode(b64)
-            .map_err(|_| "invalid base64url string")?;
+            .map_err(|_| crate::SecureGateError::InvalidBase64)?;
         
         if bytes.len() != N {
+            let got = bytes.len();
             #[cfg(feature = "zeroize")]
             zeroize::Zeroize::zeroize(&mut bytes);
-            return Err("base64url string length mismatch");
+            return Err(crate::SecureGateError::LengthMismatch { expected: N, got });
         }
         
         let mut arr = [0u8; N];
@@ -328,10 +621,104 @@ impl<const N: usize> Fixed<[u8; N]> {
     /// let key: Fixed<[u8; 32]> = Fixed::generate_random();
     /// # }
     /// ```
+    #[must_use = "dropping a freshly generated secret — did you mean to use it?"]
     #[inline]
     pub fn generate_random() -> Self {
         crate::rng::FixedRng::<N>::generate().into_inner()
     }
+
+    /// Overwrite every byte in place with fresh OS randomness.
+    ///
+    /// For rotating a key without moving it: no new value is constructed, so no
+    /// stale copy is left behind.
+    ///
+    /// Panics if the RNG fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::Fixed;
+    /// let mut key = Fixed::new([0u8; 32]);
+    /// key.fill_random();
+    /// assert_ne!(*key.expose_secret(), [0u8; 32]);
+    /// # }
+    /// ```
+    pub fn fill_random(&mut self) {
+        crate::rng::fill_os_random(&mut self.0);
+    }
+}
+
+// Key derivation — only available with `hkdf` feature
+#[cfg(feature = "hkdf")]
+impl Fixed<[u8; 32]> {
+    /// Derive an `L`-byte subkey with HKDF-Expand (RFC 5869) over **SHA-256**.
+    ///
+    /// The master key is used directly as the HKDF pseudorandom key (PRK), so it
+    /// must already be uniformly random — a generated key or the output of
+    /// HKDF-Extract. Raw Diffie-Hellman outputs need an extract step first.
+    /// SHA-256 is used because its 32-byte output matches the master key size.
+    ///
+    /// Distinct `info` labels give independent subkeys. The master stays wrapped;
+    /// only the HKDF internals see its bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `L > 255 * 32` (8160), the HKDF-SHA256 output limit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "hkdf")]
+    /// # {
+    /// use secure_gate::Fixed;
+    /// let master = Fixed::new([7u8; 32]);
+    /// let enc: Fixed<[u8; 32]> = master.hkdf_expand(b"enc");
+    /// let mac: Fixed<[u8; 32]> = master.hkdf_expand(b"mac");
+    /// assert_ne!(enc.expose_secret(), mac.expose_secret());
+    /// # }
+    /// ```
+    #[must_use = "dropping a derived secret — did you mean to use it?"]
+    pub fn hkdf_expand<const L: usize>(&self, info: &[u8]) -> Fixed<[u8; L]> {
+        let hk = hkdf::Hkdf::<sha2::Sha256>::from_prk(&self.0)
+            .expect("a 32-byte PRK is always valid for SHA-256");
+        let mut okm = [0u8; L];
+        hk.expand(info, &mut okm).expect("hkdf_expand: L exceeds 255 * 32 bytes");
+        Fixed::new(okm)
+    }
+}
+
+// Exposure counting — only available with `count-exposures` feature
+#[cfg(feature = "count-exposures")]
+impl<T> Fixed<T> {
+    /// Number of times this secret has been exposed through `expose()`,
+    /// `expose_mut()` or `expose_secret_mut()`.
+    ///
+    /// A debugging aid for spotting over-exposure in hot loops. `expose_secret()`
+    /// stays a `const fn` and is not counted. Counts live in a side table keyed by
+    /// the secret's address, so the wrapper layout is unchanged; compare two readings
+    /// rather than relying on an absolute value. Counting only happens in debug
+    /// builds; in release builds this always returns `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "count-exposures")]
+    /// # {
+    /// use secure_gate::Fixed;
+    /// let key = Fixed::new([0u8; 32]);
+    /// let before = key.exposure_count();
+    /// let _ = key.expose();
+    /// let _ = key.expose();
+    /// # #[cfg(debug_assertions)]
+    /// assert_eq!(key.exposure_count() - before, 2);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn exposure_count(&self) -> usize {
+        crate::exposure_count::count(&self.0)
+    }
 }
 
 // Zeroize integration
@@ -344,3 +731,31 @@ impl<T: zeroize::Zeroize> zeroize::Zeroize for Fixed<T> {
 
 #[cfg(feature = "zeroize")]
 impl<T: zeroize::Zeroize> zeroize::ZeroizeOnDrop for Fixed<T> {}
+
+// Serde integration — only available with `serde` feature
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Fixed<T> {
+    /// Serializes the inner value transparently.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Fixed<T> {
+    /// Deserializes the inner value transparently and wraps it.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
+
+// `secrecy` interop — only available with `secrecy-compat` feature
+#[cfg(feature = "secrecy-compat")]
+impl<T: zeroize::Zeroize> From<Fixed<T>> for secrecy::SecretBox<T> {
+    /// Boxes the value into a `SecretBox`. Like any move of a stack value, this may
+    /// leave a bitwise copy behind on the stack that is not wiped.
+    #[inline]
+    fn from(value: Fixed<T>) -> Self {
+        secrecy::SecretBox::new(alloc::boxed::Box::new(value.0))
+    }
+}
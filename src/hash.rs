@@ -0,0 +1,47 @@
+// ==========================================================================
+// src/hash.rs
+// ==========================================================================
+
+//! Opt-in `Hash` support that never feeds raw secret bytes to the hasher.
+//!
+//! The secret is first reduced to a keyed SipHash digest using a random key
+//! chosen once per process. Only that 64-bit digest reaches the caller's
+//! `Hasher`, so hasher state (and any hash values that escape, e.g. via
+//! iteration order) cannot be correlated with the secret across processes.
+//!
+//! `Fixed` and `Dynamic` still do **not** implement `Eq` — keys in a
+//! `HashMap`/`HashSet` must be wrapped in a newtype whose `PartialEq` uses
+//! `ct_eq`, so equality checks stay constant-time.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::sync::OnceLock;
+
+use crate::{Dynamic, Fixed};
+
+static DIGEST_KEY: OnceLock<RandomState> = OnceLock::new();
+
+/// Keyed digest of secret bytes — the only value that reaches a `Hasher`.
+#[inline]
+fn keyed_digest(bytes: &[u8]) -> u64 {
+    let mut hasher = DIGEST_KEY.get_or_init(RandomState::new).build_hasher();
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+impl<const N: usize> Hash for Fixed<[u8; N]> {
+    /// Hashes a per-process keyed digest of the secret, never the raw bytes.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        keyed_digest(self.expose_secret()).hash(state);
+    }
+}
+
+impl<T> Hash for Dynamic<T>
+where
+    T: ?Sized + AsRef<[u8]>,
+{
+    /// Hashes a per-process keyed digest of the secret, never the raw bytes.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        keyed_digest(self.expose_secret().as_ref()).hash(state);
+    }
+}
@@ -0,0 +1,147 @@
+// ==========================================================================
+// src/heapless_secret.rs
+// ==========================================================================
+
+use core::fmt;
+
+/// Allocator-free, variable-length secret backed by `heapless::Vec<u8, CAP>`.
+///
+/// Sits between `Fixed` (length fixed at compile time) and `Dynamic` (heap):
+/// the length can change at runtime up to `CAP` bytes, but storage is inline,
+/// so it works on embedded targets without an allocator.
+///
+/// Security invariants match the other wrappers:
+/// - No `Deref` or `AsRef` — access only via `expose_secret()` / `expose_secret_mut()`.
+/// - `Debug` is always redacted.
+/// - No `Clone`.
+/// - With `zeroize`, the full `CAP`-byte buffer (not just the live prefix) is wiped on drop.
+///
+/// Requires the "heapless" feature.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "heapless")]
+/// # {
+/// use secure_gate::HeaplessSecret;
+/// let mut pin = HeaplessSecret::<16>::from_slice(b"1234").unwrap();
+/// pin.expose_secret_mut().push(b'5').unwrap();
+/// assert_eq!(pin.expose_secret().as_slice(), b"12345");
+/// assert_eq!(format!("{pin:?}"), "[REDACTED]");
+/// # }
+/// ```
+pub struct HeaplessSecret<const CAP: usize>(heapless::Vec<u8, CAP>);
+
+impl<const CAP: usize> HeaplessSecret<CAP> {
+    /// Create an empty secret with room for `CAP` bytes.
+    #[must_use = "dropping a newly wrapped secret — did you mean to bind it?"]
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self::from_vec(heapless::Vec::new())
+    }
+
+    /// Wrap an existing `heapless::Vec`.
+    #[must_use = "dropping a newly wrapped secret — did you mean to bind it?"]
+    #[inline(always)]
+    pub const fn from_vec(value: heapless::Vec<u8, CAP>) -> Self {
+        HeaplessSecret(value)
+    }
+
+    /// Copy `bytes` into a new secret.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecureGateError::CapacityExceeded` if `bytes.len() > CAP`.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, crate::SecureGateError> {
+        heapless::Vec::from_slice(bytes)
+            .map(Self::from_vec)
+            .map_err(|_| crate::SecureGateError::CapacityExceeded {
+                capacity: CAP,
+                got: bytes.len(),
+            })
+    }
+
+    /// Expose the inner value for read-only access.
+    ///
+    /// This is the **only** way to read the secret — loud and auditable.
+    #[inline(always)]
+    pub const fn expose_secret(&self) -> &heapless::Vec<u8, CAP> {
+        &self.0
+    }
+
+    /// Expose the inner value for mutable access.
+    ///
+    /// This is the **only** way to mutate the secret — loud and auditable.
+    #[inline(always)]
+    #[cfg_attr(feature = "tracing", track_caller)]
+    pub fn expose_secret_mut(&mut self) -> &mut heapless::Vec<u8, CAP> {
+        crate::exposure_count::record(&self.0);
+        #[cfg(feature = "tracing")]
+        crate::exposure_trace::mutable_exposure("HeaplessSecret", &self.0);
+        &mut self.0
+    }
+
+    /// Current length in bytes.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the secret holds no bytes.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Maximum number of bytes this secret can hold.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        CAP
+    }
+}
+
+impl<const CAP: usize> Default for HeaplessSecret<CAP> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const CAP: usize> fmt::Debug for HeaplessSecret<CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+// Exposure counting — only available with `count-exposures` feature
+#[cfg(feature = "count-exposures")]
+impl<const CAP: usize> HeaplessSecret<CAP> {
+    /// Number of times `expose_secret_mut()` has been called on this secret.
+    ///
+    /// Always `0` in release builds.
+    #[inline(always)]
+    pub fn exposure_count(&self) -> usize {
+        crate::exposure_count::count(&self.0)
+    }
+}
+
+// Zeroize integration — wipes the whole inline buffer, including bytes past `len()`
+#[cfg(feature = "zeroize")]
+impl<const CAP: usize> zeroize::Zeroize for HeaplessSecret<CAP> {
+    fn zeroize(&mut self) {
+        // Extend to full capacity so stale bytes beyond `len()` are wiped too
+        let _ = self.0.resize(CAP, 0);
+        zeroize::Zeroize::zeroize(self.0.as_mut_slice());
+        self.0.clear();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const CAP: usize> Drop for HeaplessSecret<CAP> {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const CAP: usize> zeroize::ZeroizeOnDrop for HeaplessSecret<CAP> {}
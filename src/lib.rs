@@ -2,6 +2,7 @@
 // src/lib.rs
 // ==========================================================================
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(not(feature = "zeroize"), forbid(unsafe_code))]
 #![doc = include_str!("../README.md")]
 
@@ -11,16 +12,49 @@ extern crate alloc;
 mod dynamic;
 mod fixed;
 
-pub use dynamic::Dynamic;
-pub use fixed::Fixed;
+pub use dynamic::{concat_secrets, Dynamic};
+pub use fixed::{Fixed, MAX_FIXED_SIZE};
+
+// ── Error type (always available) ────────────────────────────────────
+mod error;
+pub use error::SecureGateError;
+
+// ── Borrowed-or-owned secret bytes (always available) ────────────────
+mod cow;
+pub use cow::SecretCow;
+
+// ── Typed exposure views (always available) ──────────────────────────
+mod expose;
+pub use expose::{Expose, ExposeMut};
+#[cfg(feature = "zeroize")]
+pub use expose::ScratchExpose;
+#[cfg(feature = "std")]
+pub use expose::SecretReader;
 
 // ── Non-cloneable wrappers (always available, zero-cost, pure) ───────
 mod no_clone;
 pub use no_clone::{DynamicNoClone, FixedNoClone};
 
+// ── Redacted type/length summaries (internal) ──────────────────────
+mod redact;
+
+// ── Exposure counts (`count-exposures` side table, debug only) ───────
+mod exposure_count;
+
+// ── Mutable-exposure trace events (`tracing` feature only) ───────────
+#[cfg(feature = "tracing")]
+mod exposure_trace;
+
 // ── Macros (always available) ────────────────────────────────────────
 mod macros;
 
+// ── Feature-matrix guards ────────────────────────────────────────────
+// `ct_eq` on `Fixed` / `Dynamic` / `HexString` comes from the `ct-eq` feature and its
+// `subtle` backend. `conversions` must keep implying it in Cargo.toml; if that link is
+// ever dropped, fail here with a clear message rather than with unresolved `subtle` paths.
+#[cfg(all(feature = "conversions", not(feature = "ct-eq")))]
+compile_error!("feature `conversions` requires `ct-eq` (the `subtle` comparison backend)");
+
 // ── Feature-gated modules (zero compile-time cost when disabled) ─────
 #[cfg(feature = "rand")]
 pub mod rng;
@@ -28,9 +62,60 @@ pub mod rng;
 #[cfg(feature = "conversions")]
 pub mod conversions;
 
+#[cfg(feature = "ct-eq")]
+mod ct;
+
+#[cfg(all(feature = "serde", feature = "conversions"))]
+pub mod serde_base64url;
+
+#[cfg(feature = "ct-eq")]
+mod secret_list;
+
+#[cfg(feature = "hash-digest")]
+mod hash;
+
+#[cfg(feature = "zeroize")]
+mod scratch;
+
+#[cfg(feature = "zeroize")]
+mod zeroizing;
+
+#[cfg(feature = "heapless")]
+mod heapless_secret;
+
 // ── Feature-gated re-exports ─────────────────────────────────────────
 #[cfg(feature = "rand")]
-pub use rng::{DynamicRng, FixedRng};
+pub use rng::{DynamicRng, FixedRng, RngConfig, RngSource};
 
 #[cfg(feature = "conversions")]
-pub use conversions::{HexString, RandomHex, SecureConversionsExt};
+pub use conversions::{HexString, HexStringBuilder, SecureConversionsExt};
+
+#[cfg(all(feature = "rand", feature = "conversions"))]
+pub use conversions::RandomHex;
+
+#[cfg(feature = "ct-eq")]
+pub use ct::{ct_any_eq, ct_eq_bytes};
+
+#[cfg(feature = "ct-eq")]
+pub use secret_list::SecretList;
+
+#[cfg(feature = "zeroize")]
+pub use scratch::{with_scratch, with_scratch_vec};
+
+#[cfg(feature = "zeroize")]
+pub use zeroizing::FixedZeroizingExt;
+
+#[cfg(feature = "heapless")]
+pub use heapless_secret::HeaplessSecret;
+
+// ── Macro support (not public API) ───────────────────────────────────
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::string::String;
+    #[cfg(feature = "ct-eq")]
+    pub use crate::ct::SecureCompare;
+    #[cfg(feature = "zeroize")]
+    pub use crate::scratch::WipeOnDrop;
+    #[cfg(feature = "zeroize")]
+    pub use zeroize;
+}
@@ -34,11 +34,37 @@
 /// ```
 ///
 /// The generated type is zero-cost and works with all features.
+///
+/// # Size guard
+///
+/// `Fixed` secrets live on the stack, so the macro rejects sizes above
+/// [`MAX_FIXED_SIZE`](crate::MAX_FIXED_SIZE) (1 MiB) at compile time instead of
+/// letting an absurd alias blow the stack at runtime. Pass `max = LIMIT` to use a
+/// different bound:
+///
+/// ```
+/// use secure_gate::fixed_alias;
+/// const EMBEDDED_MAX: usize = 256;
+/// fixed_alias!(pub SmallKey, 32, max = EMBEDDED_MAX);
+/// ```
+///
+/// ```compile_fail
+/// use secure_gate::fixed_alias;
+/// fixed_alias!(pub Huge, 100000000); // exceeds MAX_FIXED_SIZE
+/// ```
 #[macro_export]
 macro_rules! fixed_alias {
     ($vis:vis $name:ident, $size:literal) => {
+        $crate::fixed_alias!($vis $name, $size, max = $crate::MAX_FIXED_SIZE);
+    };
+    ($vis:vis $name:ident, $size:literal, max = $max:expr) => {
         #[doc = concat!("Fixed-size secure secret (", $size, " bytes)")]
         $vis type $name = $crate::Fixed<[u8; $size]>;
+
+        const _: () = assert!(
+            $size <= $max,
+            concat!("fixed_alias!: `", stringify!($name), "` is too large for a stack-allocated secret")
+        );
     };
 }
 
@@ -137,3 +163,158 @@ macro_rules! dynamic_generic_alias {
         $vis type $name = $crate::Dynamic<$inner>;
     };
 }
+
+/// Declares a struct of secret fields whose `Drop` wipes every field, panic-safely.
+///
+/// The generated `Drop` zeroizes all fields up front, each through its own guard,
+/// so if wiping one field panics the remaining guards still run during unwinding.
+/// By the time the fields' own destructors run, everything has already been wiped.
+/// `Debug` is implemented and always redacted.
+///
+/// Every field type must implement `zeroize::Zeroize` (all of this crate's wrappers do).
+/// Because the struct implements `Drop`, fields cannot be moved out of it.
+///
+/// Requires the `zeroize` feature.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "zeroize")]
+/// # {
+/// use secure_gate::{secret_bundle, Dynamic, Fixed};
+/// secret_bundle! {
+///     pub struct Session {
+///         pub key: Fixed<[u8; 32]>,
+///         pub iv: Fixed<[u8; 12]>,
+///         pub token: Dynamic<String>,
+///     }
+/// }
+/// let session = Session {
+///     key: Fixed::new([1u8; 32]),
+///     iv: Fixed::new([2u8; 12]),
+///     token: "t0ken".into(),
+/// };
+/// assert_eq!(format!("{session:?}"), "[REDACTED]");
+/// drop(session); // every field wiped, even if one wipe panics
+/// # }
+/// ```
+#[macro_export]
+macro_rules! secret_bundle {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $( $(#[$fmeta:meta])* $fvis:vis $field:ident : $ty:ty ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $( $(#[$fmeta])* $fvis $field : $ty ),*
+        }
+
+        impl ::core::ops::Drop for $name {
+            fn drop(&mut self) {
+                // One guard per field: a panicking wipe still lets the rest run on unwind
+                let _guards = ( $( $crate::__private::WipeOnDrop(&mut self.$field), )* );
+            }
+        }
+
+        impl $crate::__private::zeroize::ZeroizeOnDrop for $name {}
+
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str("[REDACTED]")
+            }
+        }
+    };
+}
+
+/// Constant-time equality between two secrets of the same type.
+///
+/// Picks the right exposure and constant-time comparison for the wrapper passed,
+/// so call sites never reach for `==` on exposed bytes. Supported types:
+/// `Fixed<[u8; N]>`, `FixedNoClone<[u8; N]>`, `Dynamic<T>` for `T: AsRef<[u8]>`
+/// (`Vec<u8>`, `String`, ...), and `HexString` / `RandomHex`. Arguments may be
+/// values or references.
+///
+/// Requires the `ct-eq` feature.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "ct-eq")]
+/// # {
+/// use secure_gate::{secure_compare, Dynamic, Fixed, FixedNoClone};
+///
+/// let a = Fixed::new([1u8; 32]);
+/// let b = Fixed::new([1u8; 32]);
+/// assert!(secure_compare!(a, b));
+///
+/// let a = FixedNoClone::new([1u8; 16]);
+/// let b = FixedNoClone::new([2u8; 16]);
+/// assert!(!secure_compare!(&a, &b));
+///
+/// let stored: Dynamic<String> = "hunter2".into();
+/// let given: Dynamic<String> = "hunter2".into();
+/// assert!(secure_compare!(stored, given));
+///
+/// let x = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+/// let y = Dynamic::<Vec<u8>>::new(vec![1, 2]);
+/// assert!(!secure_compare!(x, y));
+/// # }
+/// ```
+///
+/// ```
+/// # #[cfg(feature = "conversions")]
+/// # {
+/// use secure_gate::{secure_compare, HexString};
+/// let a = HexString::new("DEADbeef".to_string()).unwrap();
+/// let b = HexString::new("deadbeef".to_string()).unwrap();
+/// assert!(secure_compare!(a, b));
+/// # }
+/// ```
+///
+/// Mismatched types do not compile:
+///
+/// ```compile_fail
+/// use secure_gate::{secure_compare, Fixed};
+/// let a = Fixed::new([0u8; 16]);
+/// let b = Fixed::new([0u8; 32]);
+/// secure_compare!(a, b);
+/// ```
+#[macro_export]
+macro_rules! secure_compare {
+    ($a:expr, $b:expr $(,)?) => {{
+        use $crate::__private::SecureCompare as _;
+        (&$a).secure_compare(&$b)
+    }};
+}
+
+/// Formats into a [`Dynamic<String>`](crate::Dynamic) — `format!` for secrets.
+///
+/// The output is written straight into the wrapped buffer (through its
+/// `fmt::Write` impl), so the formatted secret is never a bare `String`. With
+/// `zeroize`, buffers outgrown while formatting are wiped before being freed.
+///
+/// The crate's wrappers implement only a redacted `Debug` and no `Display`, so
+/// pass `.expose_secret()` for any wrapped argument that should really be
+/// interpolated.
+///
+/// # Examples
+///
+/// ```
+/// use secure_gate::{secure_format, Dynamic};
+/// let user = "alice";
+/// let pw: Dynamic<String> = "hunter2".into();
+/// let creds = secure_format!("{user}:{}", pw.expose_secret());
+/// assert_eq!(creds.expose_secret(), "alice:hunter2");
+/// assert_eq!(format!("{creds:?}"), "[REDACTED]");
+/// ```
+#[macro_export]
+macro_rules! secure_format {
+    ($($arg:tt)*) => {{
+        let mut out = $crate::Dynamic::from($crate::__private::String::new());
+        ::core::fmt::Write::write_fmt(&mut out, ::core::format_args!($($arg)*))
+            .expect("a formatting trait implementation returned an error");
+        out
+    }};
+}
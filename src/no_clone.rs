@@ -5,6 +5,8 @@
 extern crate alloc;
 
 use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
 use core::fmt;
 
 /// Non-cloneable stack-allocated secret wrapper.
@@ -62,6 +64,7 @@ impl<T> FixedNoClone<T> {
     /// use secure_gate::FixedNoClone;
     /// let secret = FixedNoClone::new(42u32);
     /// ```
+    #[must_use = "dropping a newly wrapped secret — did you mean to bind it?"]
     #[inline(always)]
     pub const fn new(value: T) -> Self {
         FixedNoClone(value)
@@ -96,7 +99,11 @@ impl<T> FixedNoClone<T> {
     /// assert_eq!(secret.expose_secret()[0], 99);
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "tracing", track_caller)]
     pub fn expose_secret_mut(&mut self) -> &mut T {
+        crate::exposure_count::record(&self.0);
+        #[cfg(feature = "tracing")]
+        crate::exposure_trace::mutable_exposure("FixedNoClone", &self.0);
         &mut self.0
     }
 }
@@ -136,6 +143,7 @@ impl<T: ?Sized> DynamicNoClone<T> {
     /// let boxed = Box::new("secret".to_string());
     /// let no_clone = DynamicNoClone::new(boxed);
     /// ```
+    #[must_use = "dropping a newly wrapped secret — did you mean to bind it?"]
     #[inline(always)]
     pub fn new(value: Box<T>) -> Self {
         DynamicNoClone(value)
@@ -170,7 +178,11 @@ impl<T: ?Sized> DynamicNoClone<T> {
     /// assert_eq!(secret.expose_secret(), "hello world");
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "tracing", track_caller)]
     pub fn expose_secret_mut(&mut self) -> &mut T {
+        crate::exposure_count::record(&*self.0);
+        #[cfg(feature = "tracing")]
+        crate::exposure_trace::mutable_exposure("DynamicNoClone", &*self.0);
         &mut self.0
     }
 }
@@ -200,6 +212,22 @@ impl<T: ?Sized + Zeroize> DynamicNoClone<T> {
     }
 }
 
+impl<const N: usize> From<[u8; N]> for FixedNoClone<[u8; N]> {
+    /// Wrap a raw byte array in a `FixedNoClone` secret, mirroring `Fixed`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::FixedNoClone;
+    /// let key: FixedNoClone<[u8; 4]> = [1, 2, 3, 4].into();
+    /// assert_eq!(key.expose_secret(), &[1, 2, 3, 4]);
+    /// ```
+    #[inline(always)]
+    fn from(arr: [u8; N]) -> Self {
+        Self::new(arr)
+    }
+}
+
 impl<T> fmt::Debug for FixedNoClone<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("[REDACTED]")
@@ -226,6 +254,12 @@ impl DynamicNoClone<String> {
     pub const fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Returns the allocated capacity in bytes — metadata only, not secret content.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
 }
 
 impl<T> DynamicNoClone<Vec<T>> {
@@ -240,6 +274,161 @@ impl<T> DynamicNoClone<Vec<T>> {
     pub const fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Returns the allocated capacity in elements — metadata only, not secret content.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+}
+
+// Constant-time equality — only available with `ct-eq` feature (implied by `conversions`)
+#[cfg(feature = "ct-eq")]
+impl<const N: usize> FixedNoClone<[u8; N]> {
+    /// Constant-time equality comparison, mirroring `Fixed::ct_eq`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "ct-eq")]
+    /// # {
+    /// use secure_gate::FixedNoClone;
+    /// let a = FixedNoClone::new([1u8; 32]);
+    /// let b = FixedNoClone::new([1u8; 32]);
+    /// assert!(a.ct_eq(&b));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        subtle::ConstantTimeEq::ct_eq(self.0.as_slice(), other.0.as_slice()).into()
+    }
+
+    /// Constant-time comparison against an expected byte slice.
+    ///
+    /// A length mismatch returns `false`; timing depends only on `expected.len()`
+    /// (see [`ct_eq_bytes`](crate::ct_eq_bytes)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "ct-eq")]
+    /// # {
+    /// use secure_gate::FixedNoClone;
+    /// let tag = FixedNoClone::new([0xAB; 16]);
+    /// assert!(tag.ct_eq_slice(&[0xAB; 16]));
+    /// assert!(!tag.ct_eq_slice(&[0xAB; 15]));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn ct_eq_slice(&self, expected: &[u8]) -> bool {
+        crate::ct_eq_bytes(expected, &self.0)
+    }
+}
+
+// In-place randomness — only available with `rand` feature
+#[cfg(feature = "rand")]
+impl<const N: usize> FixedNoClone<[u8; N]> {
+    /// Overwrite every byte in place with fresh OS randomness, mirroring `Fixed::fill_random`.
+    ///
+    /// Rotates a single-ownership key without dropping and rebuilding the wrapper.
+    ///
+    /// Panics if the RNG fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::FixedNoClone;
+    /// let mut key = FixedNoClone::new([0u8; 32]);
+    /// key.fill_random();
+    /// assert_ne!(*key.expose_secret(), [0u8; 32]);
+    /// # }
+    /// ```
+    pub fn fill_random(&mut self) {
+        crate::rng::fill_os_random(&mut self.0);
+    }
+}
+
+#[cfg(feature = "rand")]
+impl DynamicNoClone<Vec<u8>> {
+    /// Overwrite every byte in place with fresh OS randomness, keeping the length.
+    ///
+    /// Reuses the existing allocation, mirroring `Dynamic::fill_random`.
+    ///
+    /// Panics if the RNG fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::DynamicNoClone;
+    /// let mut key = DynamicNoClone::new(Box::new(vec![0u8; 64]));
+    /// key.fill_random();
+    /// assert_eq!(key.len(), 64);
+    /// assert!(key.expose_secret().iter().any(|&b| b != 0));
+    /// # }
+    /// ```
+    pub fn fill_random(&mut self) {
+        crate::rng::fill_os_random(&mut self.0);
+    }
+}
+
+// Exposure counting — only available with `count-exposures` feature
+#[cfg(feature = "count-exposures")]
+impl<T> FixedNoClone<T> {
+    /// Number of times `expose_secret_mut()` has been called on this secret.
+    ///
+    /// `expose_secret()` stays a `const fn` and is not counted. Counts live in a side
+    /// table keyed by the secret's address, so the wrapper layout is unchanged.
+    /// Counting only happens in debug builds; in release builds this always returns `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "count-exposures")]
+    /// # {
+    /// use secure_gate::FixedNoClone;
+    /// let mut key = FixedNoClone::new([0u8; 32]);
+    /// let before = key.exposure_count();
+    /// key.expose_secret_mut()[0] = 1;
+    /// # #[cfg(debug_assertions)]
+    /// assert_eq!(key.exposure_count() - before, 1);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn exposure_count(&self) -> usize {
+        crate::exposure_count::count(&self.0)
+    }
+}
+
+// Exposure counting — only available with `count-exposures` feature
+#[cfg(feature = "count-exposures")]
+impl<T: ?Sized> DynamicNoClone<T> {
+    /// Number of times `expose_secret_mut()` has been called on this secret.
+    ///
+    /// `expose_secret()` stays a `const fn` and is not counted. Counts live in a side
+    /// table keyed by the secret's address, so the wrapper layout is unchanged.
+    /// Counting only happens in debug builds; in release builds this always returns `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "count-exposures")]
+    /// # {
+    /// use secure_gate::DynamicNoClone;
+    /// let mut pw = DynamicNoClone::new(Box::new("hunter2".to_string()));
+    /// let before = pw.exposure_count();
+    /// pw.expose_secret_mut().push('!');
+    /// # #[cfg(debug_assertions)]
+    /// assert_eq!(pw.exposure_count() - before, 1);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn exposure_count(&self) -> usize {
+        crate::exposure_count::count(&*self.0)
+    }
 }
 
 #[cfg(feature = "zeroize")]
@@ -264,3 +453,30 @@ impl<T: Zeroize> ZeroizeOnDrop for FixedNoClone<T> {}
 
 #[cfg(feature = "zeroize")]
 impl<T: ?Sized + Zeroize> ZeroizeOnDrop for DynamicNoClone<T> {}
+
+// Serde integration — only available with `serde` feature
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for FixedNoClone<T> {
+    /// Serializes the inner value transparently.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for FixedNoClone<T> {
+    /// Deserializes the inner value transparently and wraps it.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: ?Sized + serde::Serialize> serde::Serialize for DynamicNoClone<T> {
+    /// Serializes the inner value transparently.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+// No `Deserialize` for `DynamicNoClone<T>`, consistent with `Dynamic<T>`.
@@ -0,0 +1,43 @@
+// ==========================================================================
+// src/redact.rs
+// ==========================================================================
+
+use alloc::format;
+use alloc::string::String;
+
+// "<wrapper><<T>>(len=<len>)" with module paths stripped from the type name,
+// e.g. `Dynamic<Vec<u8>>(len=32)`. Only the type and length appear — never content.
+pub(crate) fn redacted_summary<T: ?Sized>(wrapper: &str, len: usize) -> String {
+    format!("{wrapper}<{}>(len={len})", short_type_name::<T>())
+}
+
+// `Debug` adaptor printing `[REDACTED len=<len>]` — the length only, never content
+#[cfg(feature = "debug-len")]
+pub(crate) struct RedactedLen(pub(crate) usize);
+
+#[cfg(feature = "debug-len")]
+impl core::fmt::Debug for RedactedLen {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "[REDACTED len={}]", self.0)
+    }
+}
+
+// `core::any::type_name` without path prefixes: `alloc::vec::Vec<u8>` -> `Vec<u8>`
+fn short_type_name<T: ?Sized>() -> String {
+    let full = core::any::type_name::<T>();
+    let mut out = String::with_capacity(full.len());
+    let mut segment_start = 0;
+    let mut chars = full.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            out.truncate(segment_start);
+            continue;
+        }
+        out.push(c);
+        if !(c.is_alphanumeric() || c == '_') {
+            segment_start = out.len();
+        }
+    }
+    out
+}
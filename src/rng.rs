@@ -2,10 +2,21 @@
 // src/rng.rs
 // ==========================================================================
 
-use crate::{Dynamic, Fixed};
+use crate::{Dynamic, DynamicNoClone, Fixed, FixedNoClone};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
 use rand::rngs::OsRng;
 use rand::TryRngCore;
 
+// Fill `buf` from the OS RNG, panicking on failure — shared by every byte-filling path
+#[inline]
+pub(crate) fn fill_os_random(buf: &mut [u8]) {
+    OsRng
+        .try_fill_bytes(buf)
+        .expect("OsRng failed — this should never happen on supported platforms");
+}
+
 /// Fixed-length cryptographically secure random value.
 ///
 /// This is a newtype over `Fixed<[u8; N]>` that enforces construction only via secure RNG.
@@ -37,11 +48,21 @@ use rand::TryRngCore;
 pub struct FixedRng<const N: usize>(Fixed<[u8; N]>);
 
 impl<const N: usize> FixedRng<N> {
+    // Post-monomorphization guard: instantiating `generate()` with an oversized `N`
+    // is a compile error instead of a stack overflow at runtime.
+    const SIZE_OK: () = assert!(
+        N <= crate::MAX_FIXED_SIZE,
+        "FixedRng size too large for stack allocation"
+    );
+
     /// Generate fresh random bytes using the OS RNG.
     ///
     /// Uses `rand::rngs::OsRng` directly for maximum throughput.
     /// Panics if the RNG fails (rare, but correct for crypto code).
     ///
+    /// `N` must not exceed [`MAX_FIXED_SIZE`](crate::MAX_FIXED_SIZE); larger sizes
+    /// are rejected at compile time ("FixedRng size too large for stack allocation").
+    ///
     /// # Example
     ///
     /// ```
@@ -52,14 +73,86 @@ impl<const N: usize> FixedRng<N> {
     /// assert!(!random.is_empty());
     /// # }
     /// ```
+    ///
+    /// ```compile_fail
+    /// use secure_gate::{rng::FixedRng, MAX_FIXED_SIZE};
+    /// let too_big = FixedRng::<{ MAX_FIXED_SIZE + 1 }>::generate();
+    /// ```
+    #[must_use = "dropping a freshly generated secret — did you mean to use it?"]
     pub fn generate() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::SIZE_OK;
         let mut bytes = [0u8; N];
-        OsRng
-            .try_fill_bytes(&mut bytes)
-            .expect("OsRng failed — this should never happen on supported platforms");
+        fill_os_random(&mut bytes);
         Self(Fixed::new(bytes))
     }
 
+    /// Generate `K` random values that are guaranteed pairwise distinct.
+    ///
+    /// Any value equal to an earlier one is regenerated until the batch has no
+    /// duplicates. Collisions are practically impossible for 16+ bytes, but real for
+    /// short human-facing codes — and the retry cost grows as `K` approaches `256^N`.
+    /// Duplicate checks use ordinary comparison: these values are not yet compared
+    /// against attacker input, so constant time is not needed here.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `K` exceeds the number of possible `N`-byte values (`256^N`),
+    /// and if the RNG fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::rng::FixedRng;
+    /// let codes = FixedRng::<2>::generate_distinct::<100>();
+    /// for (i, a) in codes.iter().enumerate() {
+    ///     for b in &codes[i + 1..] {
+    ///         assert_ne!(a.expose_secret(), b.expose_secret());
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    #[must_use = "dropping a freshly generated secret — did you mean to use it?"]
+    pub fn generate_distinct<const K: usize>() -> [Fixed<[u8; N]>; K] {
+        assert!(
+            N >= core::mem::size_of::<usize>() || K <= 1usize << (8 * N),
+            "generate_distinct: K exceeds the number of distinct N-byte values"
+        );
+        let mut out: [Fixed<[u8; N]>; K] = core::array::from_fn(|_| Self::generate().0);
+        for i in 1..K {
+            while out[..i]
+                .iter()
+                .any(|prev| prev.expose_secret() == out[i].expose_secret())
+            {
+                out[i] = Self::generate().0;
+            }
+        }
+        out
+    }
+
+    /// An endless stream of fresh random values, generated lazily.
+    ///
+    /// Each `.next()` draws a new, independent value from `OsRng`; the iterator
+    /// never returns `None`, so bound it with `.take(k)` or similar. Panics on
+    /// `.next()` if the RNG fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::rng::FixedRng;
+    /// let pads: Vec<_> = FixedRng::<32>::stream().take(4).collect();
+    /// assert_eq!(pads.len(), 4);
+    /// assert_ne!(pads[0].expose_secret(), pads[1].expose_secret());
+    /// # }
+    /// ```
+    pub fn stream() -> impl Iterator<Item = Fixed<[u8; N]>> {
+        core::iter::repeat_with(|| Self::generate().0)
+    }
+
     /// Expose the random bytes for read-only access.
     ///
     /// # Example
@@ -105,10 +198,87 @@ impl<const N: usize> FixedRng<N> {
     /// // Can now use fixed.expose_secret() as needed
     /// # }
     /// ```
+    #[must_use = "dropping a derived secret — did you mean to use it?"]
     #[inline(always)]
     pub fn into_inner(self) -> Fixed<[u8; N]> {
         self.0
     }
+
+    /// Deliberately copy the generated bytes into a cloneable `Fixed`.
+    ///
+    /// `FixedRng` has no `Clone` so fresh randomness can't be duplicated by accident.
+    /// When a second copy is genuinely needed (keep one, derive from the other),
+    /// this makes the duplication explicit and grep-able. The `FixedRng` itself is
+    /// left intact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::{Fixed, rng::FixedRng};
+    /// let seed = FixedRng::<32>::generate();
+    /// let copy: Fixed<[u8; 32]> = seed.duplicate();
+    /// assert_eq!(copy.expose_secret(), seed.expose_secret());
+    /// # }
+    /// ```
+    #[must_use = "dropping a derived secret — did you mean to use it?"]
+    #[inline]
+    pub fn duplicate(&self) -> Fixed<[u8; N]> {
+        self.0.clone_secret()
+    }
+}
+
+/// Generate `N` random `u32` words — for PRNG seeds or word-oriented schemes.
+///
+/// `N` is the number of words: `generate_u32_array::<4>()` yields 16 random bytes
+/// as `[u32; 4]`. Each word is drawn directly from `OsRng`, so no byte-to-word
+/// reinterpretation is needed.
+///
+/// Panics if the RNG fails.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "rand")]
+/// # {
+/// use secure_gate::{rng::generate_u32_array, Fixed};
+/// let seed: Fixed<[u32; 8]> = generate_u32_array::<8>();
+/// assert_eq!(seed.expose_secret().len(), 8);
+/// # }
+/// ```
+#[must_use = "dropping a freshly generated secret — did you mean to use it?"]
+pub fn generate_u32_array<const N: usize>() -> Fixed<[u32; N]> {
+    let mut words = [0u32; N];
+    for w in &mut words {
+        *w = OsRng
+            .try_next_u32()
+            .expect("OsRng failed — this should never happen on supported platforms");
+    }
+    Fixed::new(words)
+}
+
+/// Generate `N` random `u64` words; see [`generate_u32_array`].
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "rand")]
+/// # {
+/// use secure_gate::{rng::generate_u64_array, Fixed};
+/// let seed: Fixed<[u64; 4]> = generate_u64_array::<4>();
+/// assert_eq!(seed.expose_secret().len(), 4);
+/// # }
+/// ```
+#[must_use = "dropping a freshly generated secret — did you mean to use it?"]
+pub fn generate_u64_array<const N: usize>() -> Fixed<[u64; N]> {
+    let mut words = [0u64; N];
+    for w in &mut words {
+        *w = OsRng
+            .try_next_u64()
+            .expect("OsRng failed — this should never happen on supported platforms");
+    }
+    Fixed::new(words)
 }
 
 impl<const N: usize> core::fmt::Debug for FixedRng<N> {
@@ -139,6 +309,27 @@ impl<const N: usize> From<FixedRng<N>> for Fixed<[u8; N]> {
     }
 }
 
+impl<const N: usize> From<FixedRng<N>> for FixedNoClone<[u8; N]> {
+    /// Convert a `FixedRng` straight into a non-cloneable secret.
+    ///
+    /// Skips the cloneable `Fixed` intermediate, so fresh randomness goes
+    /// directly into single ownership.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::{FixedNoClone, rng::FixedRng};
+    /// let key: FixedNoClone<[u8; 32]> = FixedRng::<32>::generate().into();
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn from(rng: FixedRng<N>) -> Self {
+        rng.into_inner().no_clone()
+    }
+}
+
 /// Heap-allocated cryptographically secure random bytes.
 ///
 /// This is a newtype over `Dynamic<Vec<u8>>` for semantic clarity.
@@ -172,14 +363,179 @@ impl DynamicRng {
     /// let random = DynamicRng::generate(128);
     /// # }
     /// ```
+    #[must_use = "dropping a freshly generated secret — did you mean to use it?"]
     pub fn generate(len: usize) -> Self {
         let mut bytes = vec![0u8; len];
-        OsRng
-            .try_fill_bytes(&mut bytes)
-            .expect("OsRng failed — this should never happen on supported platforms");
+        fill_os_random(&mut bytes);
         Self(Dynamic::from(bytes))
     }
 
+    /// Generate random bytes as described by an [`RngConfig`].
+    ///
+    /// The single extensible entry point for non-default generation; plain
+    /// [`generate`](Self::generate) remains the common case. With an alphabet,
+    /// every output byte is drawn uniformly from it by rejection sampling (no
+    /// modulo bias); with `reject_zero`, zero output bytes are redrawn.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the alphabet is empty or longer than 256 bytes, if `reject_zero`
+    /// is set and the alphabet has only zero bytes, and if the RNG fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::rng::{DynamicRng, RngConfig};
+    /// let pin = DynamicRng::generate_with_config(RngConfig::new(6).alphabet(b"0123456789"));
+    /// assert!(pin.expose_secret().iter().all(u8::is_ascii_digit));
+    ///
+    /// let nonzero = DynamicRng::generate_with_config(RngConfig::new(32).reject_zero(true));
+    /// assert!(!nonzero.expose_secret().contains(&0));
+    /// # }
+    /// ```
+    #[must_use = "dropping a freshly generated secret — did you mean to use it?"]
+    pub fn generate_with_config(config: RngConfig<'_>) -> Self {
+        let RngSource::Os = config.source;
+        if let Some(alphabet) = config.alphabet {
+            assert!(
+                !alphabet.is_empty() && alphabet.len() <= 256,
+                "RngConfig alphabet must hold 1..=256 bytes"
+            );
+            assert!(
+                !config.reject_zero || alphabet.iter().any(|&c| c != 0),
+                "RngConfig: reject_zero with an all-zero alphabet can never finish"
+            );
+        }
+        // Bytes at or above `zone` are discarded so the modulo below is unbiased
+        let zone = config.alphabet.map_or(256, |a| 256 - 256 % a.len());
+
+        let mut out = Vec::with_capacity(config.len);
+        let mut pool = [0u8; 64];
+        while out.len() < config.len {
+            fill_os_random(&mut pool);
+            for &b in pool.iter().filter(|&&b| usize::from(b) < zone) {
+                if out.len() == config.len {
+                    break;
+                }
+                let v = match config.alphabet {
+                    Some(alphabet) => alphabet[usize::from(b) % alphabet.len()],
+                    None => b,
+                };
+                if !(config.reject_zero && v == 0) {
+                    out.push(v);
+                }
+            }
+        }
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut pool);
+        Self(Dynamic::from(out))
+    }
+
+    /// Generate a uniformly random alphanumeric string (`[A-Za-z0-9]`) of `len` characters.
+    ///
+    /// Uses rejection sampling over `OsRng` output: bytes `>= 248` (the largest
+    /// multiple of 62 that fits in a byte) are discarded, so every character is
+    /// equally likely — no modulo bias. Suitable for API keys, reset tokens, and
+    /// share links.
+    ///
+    /// Panics if the RNG fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::rng::DynamicRng;
+    /// let token = DynamicRng::generate_alphanumeric(24);
+    /// assert_eq!(token.len(), 24);
+    /// assert!(token.expose_secret().chars().all(|c| c.is_ascii_alphanumeric()));
+    /// # }
+    /// ```
+    #[must_use = "dropping a freshly generated secret — did you mean to use it?"]
+    pub fn generate_alphanumeric(len: usize) -> Dynamic<String> {
+        const ALPHABET: &[u8; 62] =
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+        const ZONE: u8 = 248; // 4 * 62
+
+        let mut out = String::with_capacity(len);
+        let mut pool = [0u8; 64];
+        while out.len() < len {
+            fill_os_random(&mut pool);
+            for &b in pool.iter().filter(|&&b| b < ZONE) {
+                if out.len() == len {
+                    break;
+                }
+                out.push(ALPHABET[(b % 62) as usize] as char);
+            }
+        }
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut pool);
+        Dynamic::from(out)
+    }
+
+    /// Generate a uniformly random integer in `low..high`, wrapped so it stays redacted.
+    ///
+    /// Uses rejection sampling over `OsRng`: draws below `2^64 mod (high - low)` are
+    /// discarded, so every value in the range is equally likely — no modulo bias.
+    /// For random indices into secret permutations, shuffles, or jitter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low >= high` (the range is empty), and if the RNG fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::rng::DynamicRng;
+    /// let roll = DynamicRng::gen_range_u64(1, 7);
+    /// assert!((1..7).contains(roll.expose_secret()));
+    /// # }
+    /// ```
+    #[must_use = "dropping a freshly generated secret — did you mean to use it?"]
+    pub fn gen_range_u64(low: u64, high: u64) -> Fixed<u64> {
+        assert!(low < high, "gen_range_u64: empty range (low >= high)");
+        let span = high - low;
+        // 2^64 mod span: the size of the biased remainder at the bottom of the u64 range
+        let threshold = span.wrapping_neg() % span;
+        loop {
+            let x = OsRng
+                .try_next_u64()
+                .expect("OsRng failed — this should never happen on supported platforms");
+            if x >= threshold {
+                return Fixed::new(low + x % span);
+            }
+        }
+    }
+
+    /// `usize` variant of [`gen_range_u64`](Self::gen_range_u64), e.g. for indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low >= high`, and if the RNG fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::rng::DynamicRng;
+    /// let deck = [10, 20, 30, 40];
+    /// let i = DynamicRng::gen_range_usize(0, deck.len());
+    /// assert!(*i.expose_secret() < deck.len());
+    /// # }
+    /// ```
+    #[must_use = "dropping a freshly generated secret — did you mean to use it?"]
+    pub fn gen_range_usize(low: usize, high: usize) -> Fixed<usize> {
+        assert!(low < high, "gen_range_usize: empty range (low >= high)");
+        // usize is at most 64 bits on supported targets, so the result fits back
+        let value = Self::gen_range_u64(low as u64, high as u64);
+        Fixed::new(*value.expose_secret() as usize)
+    }
+
     /// Expose the random bytes for read-only access.
     ///
     /// This is the **only** way to read the secret — loud and auditable.
@@ -213,10 +569,65 @@ impl DynamicRng {
     }
 
     /// Consume and return the inner `Dynamic<Vec<u8>>`.
+    #[must_use = "dropping a derived secret — did you mean to use it?"]
     #[inline(always)]
     pub fn into_inner(self) -> Dynamic<Vec<u8>> {
         self.0
     }
+
+    /// Deliberately copy the generated bytes into a cloneable `Dynamic`.
+    ///
+    /// The explicit counterpart of the `Clone` impl `DynamicRng` intentionally
+    /// lacks; see [`FixedRng::duplicate`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::{Dynamic, rng::DynamicRng};
+    /// let token = DynamicRng::generate(48);
+    /// let copy: Dynamic<Vec<u8>> = token.duplicate();
+    /// assert_eq!(copy.expose_secret(), token.expose_secret());
+    /// # }
+    /// ```
+    #[must_use = "dropping a derived secret — did you mean to use it?"]
+    #[inline]
+    pub fn duplicate(&self) -> Dynamic<Vec<u8>> {
+        self.0.clone_secret()
+    }
+
+    /// Move the bytes into a stack array if the length is exactly `N`.
+    ///
+    /// On success the heap buffer is wiped (with `zeroize`) before it is freed, so
+    /// only the returned array holds the value. On a length mismatch `self` is
+    /// handed back unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::rng::DynamicRng;
+    /// let key: [u8; 32] = DynamicRng::generate(32).try_into_array().unwrap();
+    ///
+    /// let short = DynamicRng::generate(16);
+    /// let short = short.try_into_array::<32>().unwrap_err();
+    /// assert_eq!(short.len(), 16);
+    /// # }
+    /// ```
+    pub fn try_into_array<const N: usize>(self) -> Result<[u8; N], Self> {
+        if self.len() != N {
+            return Err(self);
+        }
+        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+        let mut heap = self.0;
+        let mut out = [0u8; N];
+        out.copy_from_slice(heap.expose_secret());
+        #[cfg(feature = "zeroize")]
+        heap.zeroize_now();
+        Ok(out)
+    }
 }
 
 impl core::fmt::Debug for DynamicRng {
@@ -246,3 +657,91 @@ impl From<DynamicRng> for Dynamic<Vec<u8>> {
         rng.into_inner()
     }
 }
+
+impl From<DynamicRng> for DynamicNoClone<Vec<u8>> {
+    /// Convert a `DynamicRng` straight into a non-cloneable secret.
+    ///
+    /// The heap buffer is moved, not copied.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::{DynamicNoClone, rng::DynamicRng};
+    /// let random: DynamicNoClone<Vec<u8>> = DynamicRng::generate(64).into();
+    /// assert_eq!(random.len(), 64);
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn from(rng: DynamicRng) -> Self {
+        rng.into_inner().no_clone()
+    }
+}
+
+/// Options for [`DynamicRng::generate_with_config`].
+///
+/// `#[non_exhaustive]`, so new options can be added without breaking callers:
+/// start from [`RngConfig::new`] (or `Default`) and chain the setters.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "rand")]
+/// # {
+/// use secure_gate::rng::RngConfig;
+/// let config = RngConfig::new(16).alphabet(b"ABCDEF").reject_zero(true);
+/// assert_eq!(config.len, 16);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub struct RngConfig<'a> {
+    /// Number of bytes to generate.
+    pub len: usize,
+    /// Draw every byte uniformly from this set instead of the full `0..=255` range.
+    pub alphabet: Option<&'a [u8]>,
+    /// Redraw any output byte that is zero.
+    pub reject_zero: bool,
+    /// Randomness source.
+    pub source: RngSource,
+}
+
+impl<'a> RngConfig<'a> {
+    /// Plain random bytes of length `len` from the OS RNG.
+    pub const fn new(len: usize) -> Self {
+        Self {
+            len,
+            alphabet: None,
+            reject_zero: false,
+            source: RngSource::Os,
+        }
+    }
+
+    /// Draw bytes uniformly from `alphabet` (1 to 256 bytes).
+    pub const fn alphabet(mut self, alphabet: &'a [u8]) -> Self {
+        self.alphabet = Some(alphabet);
+        self
+    }
+
+    /// Redraw zero output bytes.
+    pub const fn reject_zero(mut self, reject_zero: bool) -> Self {
+        self.reject_zero = reject_zero;
+        self
+    }
+
+    /// Select the randomness source.
+    pub const fn source(mut self, source: RngSource) -> Self {
+        self.source = source;
+        self
+    }
+}
+
+/// Randomness source selector for [`RngConfig`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RngSource {
+    /// The operating system RNG (`rand::rngs::OsRng`).
+    #[default]
+    Os,
+}
@@ -0,0 +1,70 @@
+// ==========================================================================
+// src/scratch.rs
+// ==========================================================================
+
+extern crate alloc;
+
+use alloc::vec;
+use zeroize::Zeroize;
+
+/// Zeroizes the borrowed value when dropped — including during a panic unwind.
+///
+/// Also the drop guard inside `ScratchExpose`. Public only for use by
+/// `secret_bundle!`; not part of the stable API.
+#[doc(hidden)]
+pub struct WipeOnDrop<'a, T: ?Sized + Zeroize>(pub &'a mut T);
+
+impl<T: ?Sized + Zeroize> Drop for WipeOnDrop<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// Run `f` with a zero-initialized stack scratch buffer that is always wiped afterwards.
+///
+/// The buffer is zeroized before `with_scratch` returns — and also if `f` panics,
+/// via a drop guard — so intermediate key material never outlives the closure.
+///
+/// Requires the `zeroize` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "zeroize")]
+/// # {
+/// use secure_gate::with_scratch;
+/// let checksum = with_scratch::<32, _>(|buf| {
+///     buf.fill(0xAB); // e.g. derive an intermediate value
+///     buf.iter().fold(0u8, |acc, b| acc ^ b)
+/// });
+/// assert_eq!(checksum, 0);
+/// # }
+/// ```
+pub fn with_scratch<const N: usize, R>(f: impl FnOnce(&mut [u8; N]) -> R) -> R {
+    let mut buf = [0u8; N];
+    let guard = WipeOnDrop(&mut buf);
+    f(&mut *guard.0)
+}
+
+/// Heap variant of [`with_scratch`] for sizes only known at runtime.
+///
+/// The whole allocation is zeroized before returning (or on panic) and then freed.
+///
+/// Requires the `zeroize` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "zeroize")]
+/// # {
+/// use secure_gate::with_scratch_vec;
+/// let len = with_scratch_vec(4096, |buf| buf.len());
+/// assert_eq!(len, 4096);
+/// # }
+/// ```
+pub fn with_scratch_vec<R>(len: usize, f: impl FnOnce(&mut [u8]) -> R) -> R {
+    let mut buf = vec![0u8; len];
+    let guard = WipeOnDrop(&mut buf);
+    f(guard.0.as_mut_slice())
+}
@@ -0,0 +1,121 @@
+// ==========================================================================
+// src/secret_list.rs
+// ==========================================================================
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Compact list of same-size secret entries with constant-time membership.
+///
+/// Stores entries contiguously as `Vec<[u8; N]>` — ideal for blocklists of
+/// many fixed-size values (key fingerprints, revoked tokens, …).
+///
+/// `contains_ct` always scans **every** entry with `subtle`'s constant-time
+/// equality and never exits early. That makes lookups O(n), but unlike a binary
+/// search over sorted entries it leaks nothing through branching or memory-access
+/// patterns about where (or whether) the candidate was found.
+///
+/// With `zeroize`, the entries are wiped on drop, and growing the list copies
+/// into a fresh allocation and wipes the old one.
+///
+/// Requires the `ct-eq` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "ct-eq")]
+/// # {
+/// use secure_gate::SecretList;
+/// let mut revoked = SecretList::<4>::new();
+/// revoked.push([0xde, 0xad, 0xbe, 0xef]);
+/// assert!(revoked.contains_ct(&[0xde, 0xad, 0xbe, 0xef]));
+/// assert!(!revoked.contains_ct(&[0u8; 4]));
+/// # }
+/// ```
+pub struct SecretList<const N: usize>(Vec<[u8; N]>);
+
+impl<const N: usize> SecretList<N> {
+    /// Create an empty list.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Create an empty list with room for `capacity` entries.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(Vec::with_capacity(capacity))
+    }
+
+    /// Add an entry to the list.
+    #[inline]
+    pub fn push(&mut self, entry: [u8; N]) {
+        #[cfg(feature = "zeroize")]
+        crate::dynamic::wiping_reserve(&mut self.0, 1, false);
+        self.0.push(entry);
+    }
+
+    /// Number of entries — safe public metadata.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the list has no entries.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Constant-time membership test.
+    ///
+    /// Compares `candidate` against every entry and OR-accumulates the results,
+    /// so the running time depends only on the number of entries.
+    pub fn contains_ct(&self, candidate: &[u8; N]) -> bool {
+        use subtle::ConstantTimeEq;
+
+        let mut found = subtle::Choice::from(0u8);
+        for entry in self.0.iter() {
+            found |= entry.as_slice().ct_eq(candidate.as_slice());
+        }
+        found.into()
+    }
+}
+
+impl<const N: usize> Default for SecretList<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> From<Vec<[u8; N]>> for SecretList<N> {
+    #[inline(always)]
+    fn from(entries: Vec<[u8; N]>) -> Self {
+        Self(entries)
+    }
+}
+
+impl<const N: usize> fmt::Debug for SecretList<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const N: usize> zeroize::Zeroize for SecretList<N> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const N: usize> Drop for SecretList<N> {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const N: usize> zeroize::ZeroizeOnDrop for SecretList<N> {}
@@ -0,0 +1,86 @@
+// ==========================================================================
+// src/serde_base64url.rs
+// ==========================================================================
+
+//! `#[serde(with = "secure_gate::serde_base64url")]` adapter for `Fixed<[u8; N]>`.
+//!
+//! Serializes as a URL-safe, unpadded base64 string instead of the default numeric
+//! array, and checks the decoded length on the way back in. Temporary encoded and
+//! decoded buffers are wiped with `zeroize`.
+//!
+//! Requires the `serde` and `conversions` features.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(all(feature = "serde", feature = "conversions"))]
+//! # {
+//! use secure_gate::Fixed;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "secure_gate::serde_base64url")]
+//!     key: Fixed<[u8; 4]>,
+//! }
+//!
+//! let json = serde_json::to_string(&Config { key: Fixed::new([0xde, 0xad, 0xbe, 0xef]) }).unwrap();
+//! assert_eq!(json, r#"{"key":"3q2-7w"}"#);
+//! let back: Config = serde_json::from_str(&json).unwrap();
+//! assert_eq!(back.key.expose_secret(), &[0xde, 0xad, 0xbe, 0xef]);
+//! # }
+//! ```
+
+use alloc::string::String;
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::{Fixed, SecureConversionsExt};
+
+/// Serialize `value` as a URL-safe, unpadded base64 string.
+pub fn serialize<S, const N: usize>(
+    value: &Fixed<[u8; N]>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+    let mut encoded = value.expose_secret().to_base64url();
+    let result = serializer.serialize_str(&encoded);
+    #[cfg(feature = "zeroize")]
+    zeroize::Zeroize::zeroize(&mut encoded);
+    result
+}
+
+/// Deserialize a URL-safe, unpadded base64 string into a `Fixed<[u8; N]>`.
+///
+/// Fails if the string is not valid base64url or does not decode to exactly `N` bytes.
+pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<Fixed<[u8; N]>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    deserializer.deserialize_str(Base64UrlVisitor::<N>(PhantomData))
+}
+
+struct Base64UrlVisitor<const N: usize>(PhantomData<[u8; N]>);
+
+impl<const N: usize> serde::de::Visitor<'_> for Base64UrlVisitor<N> {
+    type Value = Fixed<[u8; N]>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a base64url string encoding {N} bytes")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Fixed::from_base64url(v).map_err(E::custom)
+    }
+
+    #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+    fn visit_string<E: serde::de::Error>(self, mut v: String) -> Result<Self::Value, E> {
+        let result = self.visit_str(&v);
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut v);
+        result
+    }
+}
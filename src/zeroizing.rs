@@ -0,0 +1,66 @@
+// ==========================================================================
+// src/zeroizing.rs
+// ==========================================================================
+
+use zeroize::Zeroizing;
+
+/// `Fixed`-style byte-array helpers for `zeroize::Zeroizing<[u8; N]>`.
+///
+/// The value stays a plain `Zeroizing` — wiped on drop and interoperable with any
+/// code expecting one — but gains the same `from_slice` / `len` / `is_empty`
+/// helpers as [`Fixed`](crate::Fixed). `From<[u8; N]>` comes from `zeroize` itself.
+///
+/// Unlike `Fixed`, `Zeroizing` derefs to the secret and its `Debug` is **not**
+/// redacted — prefer `Fixed` where exposure should stay explicit.
+///
+/// Requires the `zeroize` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "zeroize")]
+/// # {
+/// use secure_gate::FixedZeroizingExt;
+/// use zeroize::Zeroizing;
+///
+/// let key = Zeroizing::<[u8; 4]>::from_slice(&[1, 2, 3, 4]);
+/// assert_eq!(key.len(), 4);
+/// let same: Zeroizing<[u8; 4]> = [1, 2, 3, 4].into();
+/// assert_eq!(*key, *same);
+/// # }
+/// ```
+pub trait FixedZeroizingExt: Sized {
+    /// Create from a byte slice of exactly `N` bytes, copying straight into the
+    /// zeroizing storage.
+    ///
+    /// Panics if the slice length does not match `N`.
+    #[must_use = "dropping a newly wrapped secret — did you mean to bind it?"]
+    fn from_slice(bytes: &[u8]) -> Self;
+
+    /// Returns the fixed length in bytes — safe public metadata.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the secret is zero-length.
+    fn is_empty(&self) -> bool;
+}
+
+impl<const N: usize> FixedZeroizingExt for Zeroizing<[u8; N]> {
+    #[inline]
+    fn from_slice(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), N, "slice length mismatch");
+        // Fill in place so no unwiped temporary array is left behind
+        let mut out = Zeroizing::new([0u8; N]);
+        out.copy_from_slice(bytes);
+        out
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        N
+    }
+
+    #[inline(always)]
+    fn is_empty(&self) -> bool {
+        N == 0
+    }
+}
@@ -0,0 +1,8 @@
+// Dynamic has no `Deref` — secrets are never reachable via `*`
+use secure_gate::Dynamic;
+
+fn assert_deref<T: core::ops::Deref>() {}
+
+fn main() {
+    assert_deref::<Dynamic<Vec<u8>>>();
+}
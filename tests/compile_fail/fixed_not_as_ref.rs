@@ -0,0 +1,8 @@
+// Fixed has no `AsRef` — no silent borrowing of the secret
+use secure_gate::Fixed;
+
+fn assert_as_ref<T: AsRef<[u8]>>() {}
+
+fn main() {
+    assert_as_ref::<Fixed<[u8; 32]>>();
+}
@@ -0,0 +1,8 @@
+// Fixed is never `Copy` — duplication must be explicit
+use secure_gate::Fixed;
+
+fn assert_copy<T: Copy>() {}
+
+fn main() {
+    assert_copy::<Fixed<[u8; 32]>>();
+}
@@ -0,0 +1,8 @@
+// Fixed has no `Deref` — secrets are never reachable via `*`
+use secure_gate::Fixed;
+
+fn assert_deref<T: core::ops::Deref>() {}
+
+fn main() {
+    assert_deref::<Fixed<[u8; 32]>>();
+}
@@ -0,0 +1,9 @@
+// Fixed has no `Ord` — an ordered comparison would leak through early exit, so
+// secrets can't silently become `BTreeMap` / `BTreeSet` keys
+use secure_gate::Fixed;
+use std::collections::BTreeSet;
+
+fn main() {
+    let mut set = BTreeSet::new();
+    set.insert(Fixed::new([0u8; 32]));
+}
@@ -0,0 +1,8 @@
+// FixedNoClone must never implement `Clone`
+use secure_gate::FixedNoClone;
+
+fn assert_clone<T: Clone>() {}
+
+fn main() {
+    assert_clone::<FixedNoClone<[u8; 32]>>();
+}
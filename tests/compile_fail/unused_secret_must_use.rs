@@ -0,0 +1,9 @@
+// Freshly produced secrets are `#[must_use]` — dropping one on the floor is a bug
+#![deny(unused_must_use)]
+use secure_gate::Fixed;
+
+fn main() {
+    Fixed::new([0u8; 32]);
+    let key = Fixed::new([1u8; 32]);
+    key.no_clone();
+}
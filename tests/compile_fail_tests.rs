@@ -0,0 +1,16 @@
+// ==========================================================================
+// tests/compile_fail_tests.rs
+// ==========================================================================
+// Locks in the security model: the wrappers must NOT implement Deref, AsRef,
+// Copy, Ord, and (for no-clone types) Clone, and freshly produced secrets must be
+// `#[must_use]`. Each case in tests/compile_fail/ must fail to compile with the
+// recorded diagnostic.
+//
+// After a toolchain bump changes rustc's wording, regenerate the .stderr files
+// with `TRYBUILD=overwrite cargo test --test compile_fail_tests` and review the diff.
+
+#[test]
+fn security_invariants_do_not_compile() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile_fail/*.rs");
+}
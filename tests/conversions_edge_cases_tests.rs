@@ -5,10 +5,10 @@
 
 #![cfg(feature = "conversions")]
 
-use secure_gate::{dynamic_alias, fixed_alias, HexString, RandomHex, SecureConversionsExt};
+use secure_gate::{dynamic_alias, fixed_alias, HexString, SecureConversionsExt};
 
 #[cfg(feature = "rand")]
-use secure_gate::{Dynamic, Fixed, rng::{DynamicRng, FixedRng}};
+use secure_gate::{Dynamic, Fixed, RandomHex, rng::{DynamicRng, FixedRng}};
 
 // ──────────────────────────────────────────────────────────────
 // Basic conversions functionality
@@ -5,10 +5,13 @@
 
 #![cfg(feature = "conversions")]
 
-use secure_gate::{dynamic_alias, fixed_alias, HexString, RandomHex, SecureConversionsExt};
+use secure_gate::{
+    dynamic_alias, fixed_alias, HexString, HexStringBuilder, SecureConversionsExt,
+    SecureGateError,
+};
 
 #[cfg(feature = "rand")]
-use secure_gate::{Dynamic, Fixed, rng::{DynamicRng, FixedRng}};
+use secure_gate::{Dynamic, Fixed, RandomHex, rng::{DynamicRng, FixedRng}};
 
 // ──────────────────────────────────────────────────────────────
 // Basic conversions functionality
@@ -698,3 +701,408 @@ fn fixed_array_ct_eq() {
     assert!(!k1.expose_secret().ct_eq(k3.expose_secret()));
 }
 
+
+// ──────────────────────────────────────────────────────────────
+// to_hex_into() buffer reuse
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn to_hex_into_matches_to_hex() {
+    let bytes: Vec<u8> = (0..=255u8).collect();
+    let mut out = String::new();
+    bytes.as_slice().to_hex_into(&mut out);
+    assert_eq!(out, bytes.as_slice().to_hex());
+
+    let arr = [0xdeu8, 0xad, 0xbe, 0xef];
+    let mut out = String::new();
+    arr.to_hex_into(&mut out);
+    assert_eq!(out, arr.to_hex());
+}
+
+#[test]
+fn to_hex_into_appends_and_reuses_buffer() {
+    let mut out = String::from("key=");
+    [0x01u8, 0x02].to_hex_into(&mut out);
+    assert_eq!(out, "key=0102");
+
+    out.clear();
+    let cap = out.capacity();
+    [0xffu8, 0x00].to_hex_into(&mut out);
+    assert_eq!(out, "ff00");
+    assert_eq!(out.capacity(), cap);
+}
+
+// ──────────────────────────────────────────────────────────────
+// HexStringBuilder — chunked validation
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn hex_builder_accepts_chunks_splitting_bytes() {
+    let mut builder = HexStringBuilder::new();
+    for chunk in ["A", "bC", "", "d0", "1"] {
+        builder.push_str(chunk).unwrap();
+    }
+    assert_eq!(builder.len(), 6);
+    builder.push_str("fF").unwrap();
+    let hex = builder.finish().unwrap();
+    assert_eq!(hex.expose_secret(), "abcd01ff");
+    assert_eq!(hex.to_bytes(), vec![0xab, 0xcd, 0x01, 0xff]);
+}
+
+#[test]
+fn hex_builder_invalid_chunk_discards_buffer() {
+    let mut builder = HexStringBuilder::with_capacity(8);
+    builder.push_str("dead").unwrap();
+    assert!(builder.push_str("zz").is_err());
+    assert!(builder.is_empty());
+
+    // Builder remains usable after a rejected chunk
+    builder.push_str("beef").unwrap();
+    assert_eq!(builder.finish().unwrap().expose_secret(), "beef");
+}
+
+#[test]
+fn hex_builder_grows_past_capacity_and_rejects_non_ascii() {
+    let mut builder = HexStringBuilder::with_capacity(2);
+    for _ in 0..32 {
+        builder.push_str("0aF9").unwrap();
+    }
+    assert_eq!(builder.len(), 128);
+    assert!(builder.push_str("ab\u{e9}\u{1F600}").is_err());
+    assert!(builder.is_empty());
+}
+
+#[test]
+fn hex_builder_odd_total_length_fails() {
+    let mut builder = HexStringBuilder::new();
+    builder.push_str("abc").unwrap();
+    assert_eq!(builder.finish().unwrap_err(), SecureGateError::InvalidHex);
+}
+
+#[test]
+fn hex_builder_empty_is_valid() {
+    let hex = HexStringBuilder::default().finish().unwrap();
+    assert_eq!(hex.byte_len(), 0);
+    assert_eq!(format!("{:?}", HexStringBuilder::new()), "[REDACTED]");
+}
+
+// ──────────────────────────────────────────────────────────────
+// HexString ordering (constant-time)
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn hex_string_ord_matches_lexicographic() {
+    let hex = |s: &str| HexString::new(s.to_string()).unwrap();
+    assert!(hex("00ff") < hex("0100"));
+    assert!(hex("abcd") > hex("abcc"));
+    assert!(hex("ab") < hex("abcd"));
+    assert_eq!(hex("DEAD").cmp(&hex("dead")), std::cmp::Ordering::Equal);
+}
+
+// The exposure counter is interior-mutable, but `Ord` never reads it
+#[test]
+#[allow(clippy::mutable_key_type)]
+fn hex_string_in_btreeset_sorts_and_dedups() {
+    use std::collections::BTreeSet;
+    let set: BTreeSet<HexString> = ["ff", "00", "7f", "FF", "0a"]
+        .iter()
+        .map(|s| HexString::new(s.to_string()).unwrap())
+        .collect();
+    let sorted: Vec<&str> = set.iter().map(|h| h.expose_secret().as_str()).collect();
+    assert_eq!(sorted, ["00", "0a", "7f", "ff"]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// HexString::try_from_str / TryFrom<&str>
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn hex_string_try_from_str_matches_new() {
+    let borrowed = HexString::try_from_str("00AbCdEf").unwrap();
+    let owned = HexString::new("00AbCdEf".to_string()).unwrap();
+    assert_eq!(borrowed, owned);
+    assert_eq!(borrowed.expose_secret(), "00abcdef");
+
+    let via_trait = HexString::try_from("ff").unwrap();
+    assert_eq!(via_trait.to_bytes(), vec![0xff]);
+}
+
+#[test]
+fn hex_string_try_from_str_rejects_invalid() {
+    assert_eq!(HexString::try_from_str("abc").unwrap_err(), SecureGateError::InvalidHex);
+    assert_eq!(HexString::try_from_str("gg").unwrap_err(), SecureGateError::InvalidHex);
+    assert!(HexString::try_from("é0").is_err());
+}
+
+// ──────────────────────────────────────────────────────────────
+// HexString::from_bytes — encode-and-wrap round trip
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn hex_string_from_bytes_round_trips() {
+    let key: Vec<u8> = (0u8..=255).collect();
+    let hex = HexString::from_bytes(&key);
+    assert_eq!(hex.byte_len(), 256);
+    assert_eq!(hex.to_bytes(), key);
+    assert_eq!(hex, HexString::new(key.to_hex()).unwrap());
+    assert!(!format!("{hex:?}").contains("00010203"));
+
+    let empty = HexString::from_bytes(&[]);
+    assert!(empty.is_empty());
+}
+
+// ──────────────────────────────────────────────────────────────
+// HexString::to_bytes_ct — branchless decoding
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn hex_string_to_bytes_ct_matches_to_bytes() {
+    let all: Vec<u8> = (0u8..=255).collect();
+    let hex = HexString::from_bytes(&all);
+    assert_eq!(hex.to_bytes_ct(), all);
+
+    // Pseudo-random inputs of varying length, including mixed-case source text
+    let mut state = 0x2545_F491_4F6C_DD1Du64;
+    for len in 0..64 {
+        let bytes: Vec<u8> = (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state as u8
+            })
+            .collect();
+        let hex = HexString::new(bytes.to_hex_upper()).unwrap();
+        assert_eq!(hex.to_bytes_ct(), hex.to_bytes());
+        assert_eq!(hex.to_bytes_ct(), bytes);
+    }
+}
+
+// ──────────────────────────────────────────────────────────────
+// ct_fold_xor() — constant-time parity fold
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn ct_fold_xor_values() {
+    assert_eq!([0u8; 0].ct_fold_xor(), 0);
+    assert_eq!([0xAAu8].ct_fold_xor(), 0xAA);
+    assert_eq!([0xF0u8, 0x0F].ct_fold_xor(), 0xFF);
+    assert_eq!([0x5Au8; 4].ct_fold_xor(), 0);
+
+    // 1 ^ 2 ^ 3 ^ 4 ^ 5 ^ 6 == 7
+    let bytes: Vec<u8> = (1..=6u8).collect();
+    assert_eq!(bytes.as_slice().ct_fold_xor(), 0x07);
+}
+
+// ──────────────────────────────────────────────────────────────
+// ct_eq_at() — constant-time windowed comparison
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn ct_eq_at_matches_window() {
+    let buf = [1u8, 2, 3, 4, 5, 6, 7, 8];
+    assert!(buf.ct_eq_at(0, &[1, 2, 3]));
+    assert!(buf.ct_eq_at(5, &[6, 7, 8]));
+    assert!(buf.as_slice().ct_eq_at(2, &[3, 4]));
+    assert!(!buf.ct_eq_at(2, &[3, 5]));
+    assert!(!buf.ct_eq_at(4, &[4, 5]));
+
+    // Empty window is in bounds up to and including the end
+    assert!(buf.ct_eq_at(8, &[]));
+    assert!(!buf.ct_eq_at(9, &[]));
+}
+
+#[test]
+fn ct_eq_at_out_of_bounds_is_false() {
+    let buf = [1u8, 2, 3, 4];
+    assert!(!buf.ct_eq_at(3, &[4, 0]));
+    assert!(!buf.ct_eq_at(100, &[1]));
+    assert!(!buf.ct_eq_at(usize::MAX, &[1, 2]));
+    assert!(![0u8; 0].as_slice().ct_eq_at(0, &[0]));
+}
+
+// ──────────────────────────────────────────────────────────────
+// ct_contains() — constant-time byte membership
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn ct_contains_finds_byte_anywhere() {
+    let key = TestKey::from(vec![0x10, 0x20, 0x30, 0xFF]);
+    assert!(key.expose_secret().ct_contains(0x10));
+    assert!(key.expose_secret().ct_contains(0xFF));
+    assert!(!key.expose_secret().ct_contains(0x00));
+
+    let fixed = [7u8; 16];
+    assert!(fixed.ct_contains(7));
+    assert!(!fixed.ct_contains(8));
+    assert!(![0u8; 0].as_slice().ct_contains(0));
+}
+
+// ──────────────────────────────────────────────────────────────
+// ct_contains_window() — constant-time substring search
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn ct_contains_window_finds_pattern_anywhere() {
+    let data = TestKey::from(b"header:\xDE\xAD\xBE\xEF:trailer".to_vec());
+    let bytes = data.expose_secret();
+    assert!(bytes.ct_contains_window(b"header"));
+    assert!(bytes.ct_contains_window(&[0xDE, 0xAD, 0xBE, 0xEF]));
+    assert!(bytes.ct_contains_window(b"trailer"));
+    assert!(!bytes.ct_contains_window(&[0xAD, 0xDE]));
+
+    let fixed = [1u8, 2, 3, 4];
+    assert!(fixed.ct_contains_window(&[1, 2, 3, 4]));
+    assert!(!fixed.ct_contains_window(&[1, 2, 3, 4, 5]));
+}
+
+#[test]
+fn ct_contains_window_edge_lengths() {
+    let buf = [9u8; 3];
+    assert!(buf.ct_contains_window(&[]));
+    assert!([0u8; 0].as_slice().ct_contains_window(&[]));
+    assert!(![0u8; 0].as_slice().ct_contains_window(&[9]));
+    assert!(buf.ct_contains_window(&[9]));
+}
+
+// ──────────────────────────────────────────────────────────────
+// RandomHex::ct_eq_str — backup-code verification
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_hex_ct_eq_str_verifies_code() {
+    let code: RandomHex = FixedRng::<8>::random_hex();
+    let exact = code.expose_secret().clone();
+    assert!(code.ct_eq_str(&exact));
+    assert!(code.ct_eq_str(&exact.to_uppercase()));
+
+    let mut wrong = exact.clone();
+    let last = if wrong.ends_with('0') { "1" } else { "0" };
+    wrong.replace_range(15.., last);
+    assert!(!code.ct_eq_str(&wrong));
+    assert!(!code.ct_eq_str(&exact[..14]));
+    assert!(!code.ct_eq_str(""));
+}
+
+// ──────────────────────────────────────────────────────────────
+// Hex encoding reference vectors — output must be identical with
+// and without `ct-encoding`, so check against `format!` as an oracle
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn hex_encoding_matches_reference_for_every_byte() {
+    let all: Vec<u8> = (0..=255u8).collect();
+    let lower: String = all.iter().map(|b| format!("{b:02x}")).collect();
+    let upper: String = all.iter().map(|b| format!("{b:02X}")).collect();
+
+    assert_eq!(all.to_hex(), lower);
+    assert_eq!(all.to_hex_upper(), upper);
+
+    let mut into = String::from("prefix:");
+    all.to_hex_into(&mut into);
+    assert_eq!(into, format!("prefix:{lower}"));
+
+    let arr: [u8; 4] = [0x00, 0x9f, 0xa0, 0xff];
+    assert_eq!(arr.to_hex(), "009fa0ff");
+    assert_eq!(arr.to_hex_upper(), "009FA0FF");
+}
+
+#[test]
+fn hex_validation_matches_reference_for_every_byte() {
+    for b in 0..=255u8 {
+        let c = b as char;
+        let input: String = [c, '0'].iter().collect();
+        let expected = c.is_ascii_hexdigit();
+
+        assert_eq!(HexString::new(input.clone()).is_ok(), expected, "new({c:?})");
+        assert_eq!(HexString::try_from_str(&input).is_ok(), expected, "try_from_str({c:?})");
+
+        let mut builder = HexStringBuilder::new();
+        assert_eq!(builder.push_str(&input).is_ok(), expected, "push_str({c:?})");
+
+        if expected {
+            let normalized = format!("{}0", c.to_ascii_lowercase());
+            assert_eq!(HexString::new(input.clone()).unwrap().expose_secret(), &normalized);
+            assert_eq!(builder.finish().unwrap().expose_secret(), &normalized);
+        } else {
+            assert!(builder.is_empty());
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_hex_is_lowercase_hex() {
+    let hex = FixedRng::<32>::random_hex();
+    assert_eq!(hex.expose_secret().len(), 64);
+    assert!(hex
+        .expose_secret()
+        .bytes()
+        .all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f')));
+}
+
+// ──────────────────────────────────────────────────────────────
+// External implementors — the encoders, `ct_eq`, and `ct_bytes` are required
+// ──────────────────────────────────────────────────────────────
+
+struct ExternalKey([u8; 4]);
+
+impl SecureConversionsExt for ExternalKey {
+    fn to_hex(&self) -> String {
+        self.0.to_hex()
+    }
+
+    fn to_hex_upper(&self) -> String {
+        self.0.to_hex_upper()
+    }
+
+    fn to_base64url(&self) -> String {
+        self.0.to_base64url()
+    }
+
+    fn ct_eq(&self, other: &Self) -> bool {
+        self.0.ct_eq(&other.0)
+    }
+
+    fn ct_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[test]
+fn external_impl_gets_provided_to_hex_into() {
+    let key = ExternalKey([0xde, 0xad, 0xbe, 0xef]);
+    let mut out = String::from("id=");
+    key.to_hex_into(&mut out);
+    assert_eq!(out, "id=deadbeef");
+}
+
+#[test]
+fn external_impl_gets_provided_ct_fold_xor() {
+    assert_eq!(ExternalKey([0xF0, 0x0F, 0x01, 0x00]).ct_fold_xor(), 0xFE);
+    assert_eq!(ExternalKey([0x5A; 4]).ct_fold_xor(), 0);
+}
+
+#[test]
+fn external_impl_gets_provided_ct_eq_at() {
+    let key = ExternalKey([0xde, 0xad, 0xbe, 0xef]);
+    assert!(key.ct_eq_at(1, &[0xad, 0xbe]));
+    assert!(!key.ct_eq_at(1, &[0xad, 0xbf]));
+    assert!(!key.ct_eq_at(3, &[0xef, 0x00]));
+}
+
+#[test]
+fn external_impl_gets_provided_ct_contains() {
+    let key = ExternalKey([0xde, 0xad, 0xbe, 0xef]);
+    assert!(key.ct_contains(0xbe));
+    assert!(!key.ct_contains(0x00));
+}
+
+#[test]
+fn external_impl_gets_provided_ct_contains_window() {
+    let key = ExternalKey([0xde, 0xad, 0xbe, 0xef]);
+    assert!(key.ct_contains_window(&[0xad, 0xbe]));
+    assert!(!key.ct_contains_window(&[0xbe, 0xad]));
+    assert!(key.ct_contains_window(&[]));
+}
@@ -0,0 +1,76 @@
+// ==========================================================================
+// tests/cow_tests.rs
+// ==========================================================================
+// SecretCow: borrowed-or-owned secret bytes
+
+use secure_gate::{Dynamic, Fixed, SecretCow};
+
+// ──────────────────────────────────────────────────────────────
+// Construction and exposure
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn borrowed_from_fixed_and_dynamic() {
+    let fixed = Fixed::new([1u8, 2, 3]);
+    let dynamic = Dynamic::<Vec<u8>>::new(vec![4, 5]);
+
+    let a = SecretCow::from(&fixed);
+    let b = SecretCow::from(&dynamic);
+    assert!(a.is_borrowed() && b.is_borrowed());
+    assert_eq!(a.expose_secret(), &[1, 2, 3]);
+    assert_eq!(b.expose_secret(), &[4, 5]);
+}
+
+#[test]
+fn owned_and_into_owned() {
+    let owned = SecretCow::from(Dynamic::<Vec<u8>>::new(vec![9, 9]));
+    assert!(!owned.is_borrowed());
+    assert_eq!(owned.into_owned().expose_secret(), &[9, 9]);
+
+    let fixed = Fixed::new([7u8; 4]);
+    let copied = SecretCow::from(&fixed).into_owned();
+    assert_eq!(copied.expose_secret(), &[7, 7, 7, 7]);
+    assert_eq!(fixed.expose_secret(), &[7, 7, 7, 7]);
+}
+
+#[test]
+fn debug_is_redacted() {
+    let fixed = Fixed::new([1u8; 8]);
+    assert_eq!(format!("{:?}", SecretCow::from(&fixed)), "[REDACTED]");
+    let owned = SecretCow::from(Dynamic::<Vec<u8>>::new(vec![1]));
+    assert_eq!(format!("{owned:?}"), "[REDACTED]");
+}
+
+// ──────────────────────────────────────────────────────────────
+// Zeroize touches only the owned variant
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn zeroize_wipes_owned_only() {
+    use zeroize::Zeroize;
+
+    let mut owned = SecretCow::from(Dynamic::<Vec<u8>>::new(vec![1, 2, 3]));
+    owned.zeroize();
+    assert!(owned.expose_secret().is_empty());
+
+    let fixed = Fixed::new([5u8; 4]);
+    let mut borrowed = SecretCow::from(&fixed);
+    borrowed.zeroize();
+    assert_eq!(borrowed.expose_secret(), &[5, 5, 5, 5]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// Borrowed reads go through the owner
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(all(feature = "count-exposures", debug_assertions))]
+#[test]
+fn borrowed_reads_count_on_owner() {
+    let fixed = Fixed::new([3u8; 4]);
+    let cow = SecretCow::from(&fixed);
+    let before = fixed.exposure_count();
+    let _ = cow.expose_secret();
+    let _ = cow.expose_secret();
+    assert_eq!(fixed.exposure_count() - before, 2);
+}
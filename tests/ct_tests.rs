@@ -0,0 +1,69 @@
+// ==========================================================================
+// tests/ct_tests.rs
+// ==========================================================================
+// Generic constant-time primitives (ct-eq feature)
+
+#![cfg(feature = "ct-eq")]
+
+use secure_gate::{ct_any_eq, ct_eq_bytes, Fixed};
+
+#[test]
+fn ct_eq_bytes_equal_and_unequal() {
+    assert!(ct_eq_bytes(b"", b""));
+    assert!(ct_eq_bytes(b"same", b"same"));
+    assert!(!ct_eq_bytes(b"same", b"sane"));
+}
+
+#[test]
+fn ct_eq_bytes_different_lengths_are_unequal() {
+    assert!(!ct_eq_bytes(b"abc", b"abcd"));
+    assert!(!ct_eq_bytes(b"abcd", b"abc"));
+    assert!(!ct_eq_bytes(b"abab", b"ab"));
+    assert!(!ct_eq_bytes(b"abbb", b"ab")); // clamping to the last byte must not fake a match
+    assert!(!ct_eq_bytes(b"\0", b""));
+    assert!(!ct_eq_bytes(b"", b"x"));
+    assert!(!ct_eq_bytes(b"x", b""));
+}
+
+#[test]
+fn ct_eq_bytes_across_fixed_sizes() {
+    let a = Fixed::new([9u8; 32]);
+    let b = Fixed::new([9u8; 16]);
+    assert!(!ct_eq_bytes(a.expose_secret(), b.expose_secret()));
+    assert!(ct_eq_bytes(&a.expose_secret()[..16], b.expose_secret()));
+}
+
+// ──────────────────────────────────────────────────────────────
+// secure_compare! — dispatch per wrapper type
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn secure_compare_macro_pairings() {
+    use secure_gate::{secure_compare, Dynamic, Fixed, FixedNoClone};
+
+    assert!(secure_compare!(Fixed::new([3u8; 8]), Fixed::new([3u8; 8])));
+    assert!(!secure_compare!(Fixed::new([3u8; 8]), Fixed::new([4u8; 8])));
+
+    let a = FixedNoClone::new([5u8; 4]);
+    assert!(secure_compare!(&a, &FixedNoClone::new([5u8; 4])));
+
+    let pw: Dynamic<String> = "pw".into();
+    assert!(secure_compare!(pw, Dynamic::<String>::from("pw")));
+    assert!(!secure_compare!(pw, Dynamic::<String>::from("pw2")));
+}
+
+#[test]
+fn ct_any_eq_membership() {
+    let k1 = Fixed::new([1u8; 32]);
+    let k2 = Fixed::new([2u8; 32]);
+    let short = [2u8; 16];
+    let keys: [&[u8]; 3] = [k1.expose_secret(), &short, k2.expose_secret()];
+
+    assert!(ct_any_eq(&[1u8; 32], &keys));
+    assert!(ct_any_eq(&[2u8; 32], &keys)); // last entry still reached
+    assert!(ct_any_eq(&short, &keys));
+    assert!(!ct_any_eq(&[3u8; 32], &keys));
+    assert!(!ct_any_eq(&[2u8; 8], &keys)); // prefix is not a match
+    assert!(!ct_any_eq(&[1u8; 32], &[]));
+    assert!(ct_any_eq(b"", &[b"x".as_slice(), b"".as_slice()]));
+}
@@ -3,7 +3,7 @@
 // ==========================================================================
 // Comprehensive edge case testing for Dynamic type
 
-use secure_gate::Dynamic;
+use secure_gate::{Dynamic, SecureGateError};
 
 // ──────────────────────────────────────────────────────────────
 // Dynamic::new() edge cases
@@ -373,7 +373,7 @@ fn dynamic_debug_redacted_large() {
 // ct_eq() edge cases (feature-gated)
 // ──────────────────────────────────────────────────────────────
 
-#[cfg(feature = "conversions")]
+#[cfg(feature = "ct-eq")]
 #[test]
 fn dynamic_ct_eq_string_same() {
     let pw1 = Dynamic::<String>::new("secret".to_string());
@@ -382,7 +382,7 @@ fn dynamic_ct_eq_string_same() {
     assert!(pw1.ct_eq(&pw2));
 }
 
-#[cfg(feature = "conversions")]
+#[cfg(feature = "ct-eq")]
 #[test]
 fn dynamic_ct_eq_string_different() {
     let pw1 = Dynamic::<String>::new("secret".to_string());
@@ -391,7 +391,7 @@ fn dynamic_ct_eq_string_different() {
     assert!(!pw1.ct_eq(&pw2));
 }
 
-#[cfg(feature = "conversions")]
+#[cfg(feature = "ct-eq")]
 #[test]
 fn dynamic_ct_eq_vec_same() {
     let data1 = Dynamic::<Vec<u8>>::new(vec![1, 2, 3, 4]);
@@ -400,7 +400,7 @@ fn dynamic_ct_eq_vec_same() {
     assert!(data1.ct_eq(&data2));
 }
 
-#[cfg(feature = "conversions")]
+#[cfg(feature = "ct-eq")]
 #[test]
 fn dynamic_ct_eq_vec_different() {
     let data1 = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
@@ -409,7 +409,7 @@ fn dynamic_ct_eq_vec_different() {
     assert!(!data1.ct_eq(&data2));
 }
 
-#[cfg(feature = "conversions")]
+#[cfg(feature = "ct-eq")]
 #[test]
 fn dynamic_ct_eq_empty() {
     let empty1 = Dynamic::<Vec<u8>>::new(Vec::new());
@@ -418,7 +418,7 @@ fn dynamic_ct_eq_empty() {
     assert!(empty1.ct_eq(&empty2));
 }
 
-#[cfg(feature = "conversions")]
+#[cfg(feature = "ct-eq")]
 #[test]
 fn dynamic_ct_eq_different_lengths() {
     let short = Dynamic::<Vec<u8>>::new(vec![1, 2]);
@@ -428,7 +428,7 @@ fn dynamic_ct_eq_different_lengths() {
     assert!(!long.ct_eq(&short));
 }
 
-#[cfg(feature = "conversions")]
+#[cfg(feature = "ct-eq")]
 #[test]
 fn dynamic_ct_eq_one_byte_different() {
     let data1 = Dynamic::<Vec<u8>>::new(vec![42u8; 32]);
@@ -439,7 +439,7 @@ fn dynamic_ct_eq_one_byte_different() {
     assert!(!data1.ct_eq(&data2));
 }
 
-#[cfg(feature = "conversions")]
+#[cfg(feature = "ct-eq")]
 #[test]
 fn dynamic_ct_eq_string_vs_vec() {
     // These should not be comparable (different types)
@@ -498,6 +498,55 @@ fn dynamic_generate_random_large() {
     assert!(!data.expose_secret().iter().all(|&b| b == 0));
 }
 
+#[cfg(feature = "rand")]
+#[test]
+fn dynamic_fill_random_reuses_allocation() {
+    let mut key = Dynamic::<Vec<u8>>::new(vec![0u8; 64]);
+    let ptr = key.expose_secret().as_ptr();
+    let cap = key.expose_secret().capacity();
+
+    key.fill_random();
+    let first = key.expose_secret().clone();
+    key.fill_random();
+
+    assert_eq!(key.len(), 64);
+    assert_eq!(key.expose_secret().as_ptr(), ptr);
+    assert_eq!(key.expose_secret().capacity(), cap);
+    assert!(first.iter().any(|&b| b != 0));
+    assert_ne!(*key.expose_secret(), first);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn dynamic_fill_random_empty_is_noop() {
+    let mut empty = Dynamic::<Vec<u8>>::new(Vec::new());
+    empty.fill_random();
+    assert!(empty.is_empty());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn dynamic_shuffle_preserves_multiset() {
+    let original: Vec<u8> = (0..=255).chain([7, 7, 7]).collect();
+    let mut deck = Dynamic::<Vec<u8>>::new(original.clone());
+    let mut sorted = original.clone();
+    sorted.sort_unstable();
+
+    let mut moved = false;
+    for _ in 0..8 {
+        deck.shuffle();
+        moved |= *deck.expose_secret() != original;
+        let mut seen = deck.expose_secret().clone();
+        seen.sort_unstable();
+        assert_eq!(seen, sorted);
+    }
+    assert!(moved);
+
+    let mut empty = Dynamic::<Vec<u8>>::new(Vec::new());
+    empty.shuffle();
+    assert!(empty.is_empty());
+}
+
 #[cfg(feature = "rand")]
 #[test]
 fn dynamic_generate_random_multiple_different() {
@@ -625,6 +674,34 @@ fn dynamic_no_clone_empty() {
     assert!(no_clone_vec.is_empty());
 }
 
+// ──────────────────────────────────────────────────────────────
+// zeroizing_clone() — self-wiping temporary copies
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_zeroizing_clone_string() {
+    let pw = Dynamic::<String>::new("hunter2".to_string());
+    let tmp: zeroize::Zeroizing<String> = pw.zeroizing_clone();
+
+    assert_eq!(tmp.as_str(), "hunter2");
+    // Independent copy: the original is untouched
+    assert_ne!(tmp.as_ptr(), pw.expose_secret().as_ptr());
+    drop(tmp);
+    assert_eq!(pw.expose_secret(), "hunter2");
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_zeroizing_clone_vec() {
+    let key = Dynamic::<Vec<u8>>::new(vec![0xAB; 32]);
+    let mut tmp: zeroize::Zeroizing<Vec<u8>> = key.zeroizing_clone();
+
+    assert_eq!(*tmp, vec![0xAB; 32]);
+    tmp[0] = 0;
+    assert_eq!(key.expose_secret()[0], 0xAB);
+}
+
 // ──────────────────────────────────────────────────────────────
 // zeroize_now() explicit zeroization
 // ──────────────────────────────────────────────────────────────
@@ -891,3 +968,902 @@ fn dynamic_multiple_types_together() {
     assert_eq!(ints.expose_secret(), &[10, 20, 30]);
 }
 
+
+// ──────────────────────────────────────────────────────────────
+// replace() for key rotation
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_replace_returns_old_secret() {
+    let mut key = Dynamic::<Vec<u8>>::new(vec![1u8; 32]);
+    let old = key.replace(vec![2u8; 16]);
+
+    assert_eq!(old.expose_secret(), &[1u8; 32]);
+    assert_eq!(key.expose_secret(), &[2u8; 16]);
+    assert_eq!(format!("{old:?}"), "[REDACTED]");
+}
+
+#[test]
+fn dynamic_replace_string() {
+    let mut pw: Dynamic<String> = "old-password".into();
+    let old = pw.replace("new-password".to_string());
+
+    assert_eq!(old.expose_secret(), "old-password");
+    assert_eq!(pw.expose_secret(), "new-password");
+}
+
+// ──────────────────────────────────────────────────────────────
+// push_char() / push_str() / push_secret() on Dynamic<String>
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_string_push_char_builds_passphrase() {
+    let mut passphrase: Dynamic<String> = "".into();
+    for word in ["correct", "horse", "battery"] {
+        if !passphrase.is_empty() {
+            passphrase.push_char('-');
+        }
+        passphrase.push_str(word);
+    }
+    passphrase.push_char('é');
+    passphrase.push_char('🔑');
+
+    assert_eq!(passphrase.expose_secret(), "correct-horse-batteryé🔑");
+    assert_eq!(passphrase.len(), 21 + 2 + 4);
+}
+
+#[test]
+fn dynamic_string_push_char_grows_amortized() {
+    let mut pw: Dynamic<String> = "".into();
+    let mut reallocs = 0;
+    let mut cap = pw.expose_secret().capacity();
+    for _ in 0..1024 {
+        pw.push_char('x');
+        if pw.expose_secret().capacity() != cap {
+            cap = pw.expose_secret().capacity();
+            reallocs += 1;
+        }
+    }
+    assert_eq!(pw.len(), 1024);
+    assert!(
+        reallocs <= 12,
+        "expected amortized growth, saw {reallocs} reallocations"
+    );
+}
+
+#[test]
+fn dynamic_string_push_secret_builds_composite() {
+    let mut creds: Dynamic<String> = "user".into();
+    let password: Dynamic<String> = "p4ss".into();
+
+    creds.push_str(":");
+    creds.push_secret(&password);
+
+    assert_eq!(creds.expose_secret(), "user:p4ss");
+    assert_eq!(password.expose_secret(), "p4ss"); // operand untouched
+}
+
+#[test]
+fn dynamic_string_push_secret_empty_operands() {
+    let mut empty: Dynamic<String> = "".into();
+    empty.push_secret(&Dynamic::<String>::new(String::new()));
+    empty.push_str("");
+    assert!(empty.is_empty());
+
+    empty.push_secret(&"abc".into());
+    assert_eq!(empty.expose_secret(), "abc");
+}
+
+// ──────────────────────────────────────────────────────────────
+// xor_with() on Dynamic<Vec<u8>>
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_xor_with_masks_and_unmasks() {
+    let mut data = Dynamic::<Vec<u8>>::new(b"attack at dawn".to_vec());
+    let keystream = [0x5Au8; 14];
+    data.xor_with(&keystream).unwrap();
+    assert_ne!(data.expose_secret().as_slice(), b"attack at dawn");
+    data.xor_with(&keystream).unwrap();
+    assert_eq!(data.expose_secret().as_slice(), b"attack at dawn");
+}
+
+#[test]
+fn dynamic_xor_with_length_mismatch_leaves_data_untouched() {
+    let mut data = Dynamic::<Vec<u8>>::new(vec![1u8, 2, 3]);
+    assert_eq!(
+        data.xor_with(&[0xFF; 2]).unwrap_err(),
+        SecureGateError::LengthMismatch { expected: 3, got: 2 }
+    );
+    assert!(data.xor_with(&[0xFF; 4]).is_err());
+    assert_eq!(data.expose_secret(), &[1, 2, 3]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// from_hex() / from_base64url() decoders
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "conversions")]
+#[test]
+fn dynamic_from_hex_roundtrip() {
+    use secure_gate::SecureConversionsExt;
+    let key = Dynamic::<Vec<u8>>::from_hex("00ff10AB").unwrap();
+    assert_eq!(key.expose_secret(), &[0x00, 0xff, 0x10, 0xab]);
+    assert_eq!(key.expose_secret().to_hex(), "00ff10ab");
+    assert!(Dynamic::<Vec<u8>>::from_hex("").unwrap().is_empty());
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn dynamic_from_hex_rejects_invalid() {
+    assert_eq!(Dynamic::<Vec<u8>>::from_hex("abc").unwrap_err(), SecureGateError::InvalidHex);
+    assert_eq!(Dynamic::<Vec<u8>>::from_hex("zz").unwrap_err(), SecureGateError::InvalidHex);
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn dynamic_from_base64url_roundtrip() {
+    use secure_gate::SecureConversionsExt;
+    let original: Vec<u8> = (0..=100u8).collect();
+    let b64 = original.as_slice().to_base64url();
+    let key = Dynamic::<Vec<u8>>::from_base64url(&b64).unwrap();
+    assert_eq!(key.expose_secret(), &original);
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn dynamic_from_base64url_rejects_invalid() {
+    assert_eq!(
+        Dynamic::<Vec<u8>>::from_base64url("not base64!").unwrap_err(),
+        SecureGateError::InvalidBase64
+    );
+    // Padding is not accepted by the no-pad engine
+    assert!(Dynamic::<Vec<u8>>::from_base64url("3q2-7w==").is_err());
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn dynamic_encoded_secrets_stay_wrapped() {
+    let key = Dynamic::<Vec<u8>>::new(vec![0x00, 0xff, 0x10, 0xab]);
+
+    let hex: Dynamic<String> = key.to_hex_secret();
+    assert_eq!(hex.expose_secret(), "00ff10ab");
+    assert_eq!(format!("{hex:?}"), "[REDACTED]");
+    let back = Dynamic::<Vec<u8>>::from_hex(hex.expose_secret()).unwrap();
+    assert_eq!(back.expose_secret(), key.expose_secret());
+
+    let b64: Dynamic<String> = key.to_base64url_secret();
+    let back = Dynamic::<Vec<u8>>::from_base64url(b64.expose_secret()).unwrap();
+    assert_eq!(back.expose_secret(), key.expose_secret());
+
+    assert!(Dynamic::<Vec<u8>>::new(Vec::new()).to_hex_secret().is_empty());
+}
+
+// ──────────────────────────────────────────────────────────────
+// clone_secret() — explicit, grep-able duplication
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_clone_secret_is_independent_copy() {
+    let mut original = Dynamic::<Vec<u8>>::new(vec![1u8, 2, 3]);
+    let copy = original.clone_secret();
+    original.expose_secret_mut()[0] = 9;
+    assert_eq!(copy.expose_secret(), &[1, 2, 3]);
+    assert_eq!(original.expose_secret(), &[9, 2, 3]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// secure_clear() — wipe and release the allocation
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_secure_clear_releases_capacity() {
+    let mut buf = Dynamic::<Vec<u8>>::new(vec![0x42u8; 1 << 16]);
+    buf.secure_clear();
+    assert!(buf.is_empty());
+    assert_eq!(buf.expose_secret().capacity(), 0);
+
+    // Still usable afterwards
+    buf.expose_secret_mut().extend_from_slice(&[1, 2, 3]);
+    assert_eq!(buf.expose_secret(), &[1, 2, 3]);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_zeroize_now_keeps_capacity_unlike_secure_clear() {
+    let mut buf = Dynamic::<Vec<u8>>::new(vec![0x42u8; 256]);
+    buf.zeroize_now();
+    assert!(buf.expose_secret().capacity() >= 256);
+}
+
+// ──────────────────────────────────────────────────────────────
+// ct_eq_str() — password-check shape
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "ct-eq")]
+#[test]
+fn dynamic_ct_eq_str_matches_only_exact() {
+    let stored: Dynamic<String> = "correct horse".into();
+    assert!(stored.ct_eq_str("correct horse"));
+    assert!(!stored.ct_eq_str("correct hors"));
+    assert!(!stored.ct_eq_str("correct horsee"));
+    assert!(!stored.ct_eq_str("Correct horse"));
+    assert!(!stored.ct_eq_str(""));
+}
+
+#[cfg(feature = "ct-eq")]
+#[test]
+fn dynamic_ct_eq_str_repeated_secret_is_not_fooled_by_longer_candidate() {
+    // Candidate cycles over the stored bytes — must still fail on length
+    let stored: Dynamic<String> = "ab".into();
+    assert!(!stored.ct_eq_str("abab"));
+}
+
+#[cfg(feature = "ct-eq")]
+#[test]
+fn dynamic_ct_eq_str_empty_secret() {
+    let stored: Dynamic<String> = "".into();
+    assert!(stored.ct_eq_str(""));
+    assert!(!stored.ct_eq_str("x"));
+}
+
+// ──────────────────────────────────────────────────────────────
+// to_fixed_chunks() — split into independent Fixed blocks
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_to_fixed_chunks_copies_blocks() {
+    let mut stream = Dynamic::<Vec<u8>>::new((0u8..32).collect::<Vec<u8>>());
+    let blocks = stream.to_fixed_chunks::<16>().unwrap();
+    assert_eq!(blocks.len(), 2);
+    assert_eq!(blocks[0].expose_secret()[0], 0);
+    assert_eq!(blocks[1].expose_secret()[15], 31);
+
+    // Blocks are independent of the source
+    stream.expose_secret_mut().fill(0);
+    assert_eq!(blocks[1].expose_secret()[0], 16);
+}
+
+#[test]
+fn dynamic_to_fixed_chunks_rejects_ragged_length() {
+    let stream = Dynamic::<Vec<u8>>::new(vec![0u8; 10]);
+    assert_eq!(
+        stream.to_fixed_chunks::<4>().unwrap_err(),
+        SecureGateError::ChunkMisaligned { chunk_size: 4, len: 10 }
+    );
+    assert!(stream.to_fixed_chunks::<0>().is_err());
+    assert!(Dynamic::<Vec<u8>>::new(Vec::new())
+        .to_fixed_chunks::<8>()
+        .unwrap()
+        .is_empty());
+}
+
+// ──────────────────────────────────────────────────────────────
+// truncate_secure() — wipe the dropped tail
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_truncate_secure_shortens() {
+    let mut pw: Dynamic<String> = "short".into();
+    pw.push_str("-and-secret-suffix");
+    pw.truncate_secure(5);
+    assert_eq!(pw.expose_secret(), "short");
+
+    // No-op when not shorter
+    pw.truncate_secure(100);
+    assert_eq!(pw.expose_secret(), "short");
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_truncate_secure_zeroes_removed_region() {
+    let mut pw: Dynamic<String> = "keep".into();
+    pw.push_str("TOPSECRETSUFFIX");
+    let old_len = pw.len();
+    pw.truncate_secure(4);
+
+    let s = pw.expose_secret();
+    assert!(s.capacity() >= old_len);
+    // SAFETY: `old_len <= capacity` and those bytes were initialized before truncation
+    let backing = unsafe { std::slice::from_raw_parts(s.as_ptr(), old_len) };
+    assert_eq!(&backing[..4], b"keep");
+    assert!(backing[4..].iter().all(|&b| b == 0));
+}
+
+#[test]
+#[should_panic(expected = "char boundary")]
+fn dynamic_truncate_secure_rejects_mid_char() {
+    let mut pw: Dynamic<String> = "pé".into();
+    pw.truncate_secure(2); // inside the two-byte 'é'
+}
+
+// ──────────────────────────────────────────────────────────────
+// checked_truncate() — explicit errors instead of silent no-ops
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_checked_truncate_bytes() {
+    let mut buf = Dynamic::<Vec<u8>>::new(vec![1u8, 2, 3, 4]);
+    assert_eq!(
+        buf.checked_truncate(5).unwrap_err(),
+        SecureGateError::OutOfBounds { index: 5, len: 4 }
+    );
+    assert_eq!(buf.expose_secret(), &[1, 2, 3, 4]);
+
+    buf.checked_truncate(4).unwrap();
+    assert_eq!(buf.len(), 4);
+    buf.checked_truncate(1).unwrap();
+    assert_eq!(buf.expose_secret(), &[1]);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_checked_truncate_bytes_zeroes_removed_region() {
+    let mut buf = Dynamic::<Vec<u8>>::new(vec![0xEEu8; 16]);
+    buf.checked_truncate(4).unwrap();
+
+    let v = buf.expose_secret();
+    // SAFETY: `16 <= capacity` and those bytes were initialized before truncation
+    let backing = unsafe { std::slice::from_raw_parts(v.as_ptr(), 16) };
+    assert_eq!(&backing[..4], &[0xEE; 4]);
+    assert!(backing[4..].iter().all(|&b| b == 0));
+}
+
+#[test]
+fn dynamic_checked_truncate_string() {
+    let mut pw: Dynamic<String> = "pé-secret".into();
+    assert_eq!(
+        pw.checked_truncate(2).unwrap_err(),
+        SecureGateError::InvalidUtf8
+    );
+    assert_eq!(
+        pw.checked_truncate(100).unwrap_err(),
+        SecureGateError::OutOfBounds { index: 100, len: 10 }
+    );
+    assert_eq!(pw.expose_secret(), "pé-secret");
+
+    pw.checked_truncate(3).unwrap();
+    assert_eq!(pw.expose_secret(), "pé");
+}
+
+// ──────────────────────────────────────────────────────────────
+// prepend() — framing inside the secret boundary
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_prepend_inserts_at_front() {
+    let mut frame = Dynamic::<Vec<u8>>::new(vec![3u8, 4, 5]);
+    frame.prepend(&[1, 2]);
+    assert_eq!(frame.expose_secret(), &[1, 2, 3, 4, 5]);
+
+    frame.prepend(&[]);
+    assert_eq!(frame.len(), 5);
+}
+
+#[test]
+fn dynamic_prepend_grows_and_into_empty() {
+    let mut frame = Dynamic::<Vec<u8>>::new(Vec::new());
+    frame.prepend(b"tail");
+    frame.prepend(&[0xAA; 100]);
+    assert_eq!(frame.len(), 104);
+    assert_eq!(&frame.expose_secret()[100..], b"tail");
+    assert!(frame.expose_secret()[..100].iter().all(|&b| b == 0xAA));
+}
+
+// ──────────────────────────────────────────────────────────────
+// extend_from_secret() — non-consuming accumulation
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_extend_from_secret_preserves_source() {
+    let a = Dynamic::<Vec<u8>>::new(vec![1u8, 2]);
+    let b = Dynamic::<Vec<u8>>::new(vec![0xBB; 200]);
+    let mut transcript = Dynamic::<Vec<u8>>::new(vec![0u8]);
+
+    transcript.extend_from_secret(&a);
+    transcript.extend_from_secret(&b);
+    transcript.extend_from_secret(&a);
+    transcript.extend_from_secret(&Dynamic::<Vec<u8>>::new(Vec::new()));
+
+    let out = transcript.expose_secret();
+    assert_eq!(out.len(), 205);
+    assert_eq!(&out[..3], &[0, 1, 2]);
+    assert!(out[3..203].iter().all(|&x| x == 0xBB));
+    assert_eq!(&out[203..], &[1, 2]);
+    assert_eq!(a.expose_secret(), &[1, 2]);
+    assert_eq!(b.len(), 200);
+}
+
+// ──────────────────────────────────────────────────────────────
+// map_boxed() — scoped transformation
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_map_boxed_changes_type() {
+    let bytes = Dynamic::<Vec<u8>>::new(b"s3cret".to_vec());
+    let text: Dynamic<String> = bytes.map_boxed(|b| Box::new(String::from_utf8(*b).unwrap()));
+    assert_eq!(text.expose_secret(), "s3cret");
+}
+
+#[test]
+fn dynamic_map_boxed_reuses_allocation() {
+    let original = Dynamic::<Vec<u8>>::new(vec![1u8, 2, 3]);
+    let mut seen = std::ptr::null();
+    let mapped = original.map_boxed(|mut b| {
+        b.push(4);
+        seen = b.as_ptr();
+        b
+    });
+    assert_eq!(mapped.expose_secret(), &[1, 2, 3, 4]);
+    assert_eq!(mapped.expose_secret().as_ptr(), seen);
+}
+
+// ──────────────────────────────────────────────────────────────
+// reserve() / reserve_exact() — wiped reallocation
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_reserve_keeps_contents() {
+    let mut buf = Dynamic::<Vec<u8>>::new(vec![7u8; 8]);
+    buf.reserve(1000);
+    assert!(buf.expose_secret().capacity() >= 1008);
+    assert_eq!(buf.expose_secret(), &[7u8; 8]);
+
+    // Enough room already: no reallocation
+    let ptr = buf.expose_secret().as_ptr();
+    buf.reserve(10);
+    assert_eq!(buf.expose_secret().as_ptr(), ptr);
+}
+
+#[test]
+fn dynamic_reserve_exact_does_not_over_allocate() {
+    let mut buf = Dynamic::<Vec<u8>>::new(Vec::with_capacity(0));
+    buf.expose_secret_mut().extend_from_slice(&[1, 2, 3]);
+    buf.reserve_exact(5);
+    let cap = buf.expose_secret().capacity();
+    assert!(cap >= 8);
+    buf.expose_secret_mut().extend_from_slice(&[4, 5, 6, 7, 8]);
+    assert_eq!(buf.expose_secret().capacity(), cap);
+    assert_eq!(buf.expose_secret(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn dynamic_try_reserve_grows_or_fails_gracefully() {
+    let mut buf = Dynamic::<Vec<u8>>::new(vec![7u8; 8]);
+    buf.try_reserve(100).unwrap();
+    assert!(buf.expose_secret().capacity() >= 108);
+    buf.try_reserve_exact(4).unwrap();
+
+    // Attacker-sized requests are reported, not aborted on; the secret is untouched
+    let cap = buf.expose_secret().capacity();
+    assert!(buf.try_reserve(usize::MAX).is_err());
+    assert!(buf.try_reserve_exact(isize::MAX as usize).is_err());
+    assert_eq!(buf.expose_secret(), &[7u8; 8]);
+    assert_eq!(buf.expose_secret().capacity(), cap);
+}
+
+// ──────────────────────────────────────────────────────────────
+// drain_chunks_zeroizing() — streaming a secret out
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_drain_chunks_zeroizing_yields_all_and_empties() {
+    let mut buf = Dynamic::<Vec<u8>>::new((0u8..10).collect::<Vec<u8>>());
+    let chunks: Vec<Vec<u8>> = buf
+        .drain_chunks_zeroizing(3)
+        .map(|c| c.to_vec())
+        .collect();
+    assert_eq!(chunks, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7, 8], vec![9]]);
+    assert!(buf.is_empty());
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_drain_chunks_zeroizing_early_drop_keeps_remainder() {
+    let mut buf = Dynamic::<Vec<u8>>::new((1u8..=8).collect::<Vec<u8>>());
+    {
+        let mut it = buf.drain_chunks_zeroizing(3);
+        assert_eq!(it.next().unwrap().as_slice(), &[1, 2, 3]);
+    }
+    assert_eq!(buf.expose_secret(), &[4, 5, 6, 7, 8]);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+#[should_panic(expected = "chunk size must be non-zero")]
+fn dynamic_drain_chunks_zeroizing_rejects_zero_size() {
+    let mut buf = Dynamic::<Vec<u8>>::new(vec![1u8]);
+    let _ = buf.drain_chunks_zeroizing(0);
+}
+
+// ──────────────────────────────────────────────────────────────
+// xor() — non-mutating XOR into a fresh secret
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_xor_split_and_recombine() {
+    let secret = Dynamic::<Vec<u8>>::new(b"split me".to_vec());
+    let share_a = Dynamic::<Vec<u8>>::new(vec![0xA5; 8]);
+    let share_b = secret.xor(&share_a).unwrap();
+
+    assert_eq!(secret.expose_secret().as_slice(), b"split me"); // untouched
+    assert_eq!(share_a.expose_secret(), &[0xA5; 8]); // untouched
+    assert_eq!(share_b.xor(&share_a).unwrap().expose_secret().as_slice(), b"split me");
+}
+
+#[test]
+fn dynamic_xor_length_mismatch() {
+    let a = Dynamic::<Vec<u8>>::new(vec![0u8; 4]);
+    let b = Dynamic::<Vec<u8>>::new(vec![0u8; 5]);
+    assert_eq!(
+        a.xor(&b).unwrap_err(),
+        SecureGateError::LengthMismatch { expected: 4, got: 5 }
+    );
+}
+
+// ──────────────────────────────────────────────────────────────
+// split_copy() — non-consuming split into two secrets
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_split_copy_keeps_original() {
+    let okm = Dynamic::<Vec<u8>>::new((0u8..64).collect::<Vec<u8>>());
+    let (enc, mac) = okm.split_copy(32).unwrap();
+    assert_eq!(enc.expose_secret().as_slice(), &okm.expose_secret()[..32]);
+    assert_eq!(mac.expose_secret().as_slice(), &okm.expose_secret()[32..]);
+    assert_eq!(okm.len(), 64);
+
+    let (empty, all) = okm.split_copy(0).unwrap();
+    assert!(empty.is_empty());
+    assert_eq!(all.expose_secret(), okm.expose_secret());
+    let (all, empty) = okm.split_copy(64).unwrap();
+    assert_eq!(all.len(), 64);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn dynamic_split_copy_out_of_bounds() {
+    let buf = Dynamic::<Vec<u8>>::new(vec![1u8; 4]);
+    assert_eq!(
+        buf.split_copy(5).unwrap_err(),
+        SecureGateError::OutOfBounds { index: 5, len: 4 }
+    );
+}
+
+// ──────────────────────────────────────────────────────────────
+// copy_from_secret() — in-place overwrite from another secret
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_copy_from_secret_reuses_allocation() {
+    let mut buf = Dynamic::<Vec<u8>>::new(vec![0u8; 16]);
+    let ptr = buf.expose_secret().as_ptr();
+    let fresh = Dynamic::<Vec<u8>>::new(vec![7u8; 16]);
+
+    buf.copy_from_secret(&fresh).unwrap();
+    assert_eq!(buf.expose_secret(), &[7u8; 16]);
+    assert_eq!(buf.expose_secret().as_ptr(), ptr);
+    assert_eq!(fresh.expose_secret(), &[7u8; 16]); // source untouched
+}
+
+#[test]
+fn dynamic_copy_from_secret_length_mismatch() {
+    let mut buf = Dynamic::<Vec<u8>>::new(vec![1u8; 4]);
+    let short = Dynamic::<Vec<u8>>::new(vec![9u8; 3]);
+    assert_eq!(
+        buf.copy_from_secret(&short).unwrap_err(),
+        SecureGateError::LengthMismatch { expected: 4, got: 3 }
+    );
+    assert_eq!(buf.expose_secret(), &[1u8; 4]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// concat_secrets() — single-allocation concatenation
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn concat_secrets_joins_in_order_with_exact_capacity() {
+    let a = Dynamic::<Vec<u8>>::new(vec![1, 2]);
+    let b = Dynamic::<Vec<u8>>::new(Vec::new());
+    let c = Dynamic::<Vec<u8>>::new(vec![3, 4, 5]);
+
+    let joined = secure_gate::concat_secrets(&[&a, &b, &c]);
+    assert_eq!(joined.expose_secret(), &[1, 2, 3, 4, 5]);
+    assert_eq!(joined.expose_secret().capacity(), 5);
+
+    // Inputs are untouched
+    assert_eq!(a.expose_secret(), &[1, 2]);
+    assert_eq!(c.expose_secret(), &[3, 4, 5]);
+}
+
+#[test]
+fn concat_secrets_empty_list() {
+    let joined = secure_gate::concat_secrets(&[]);
+    assert!(joined.is_empty());
+}
+
+// ──────────────────────────────────────────────────────────────
+// retain_secure() — filtering with wiped removals
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn retain_secure_keeps_order() {
+    let mut data = Dynamic::<Vec<u8>>::new(vec![1, 0, 2, 0, 0, 3]);
+    data.retain_secure(|b| b != 0);
+    assert_eq!(data.expose_secret(), &[1, 2, 3]);
+}
+
+#[test]
+fn retain_secure_all_and_none() {
+    let mut all = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    all.retain_secure(|_| true);
+    assert_eq!(all.expose_secret(), &[1, 2, 3]);
+
+    let mut none = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    none.retain_secure(|_| false);
+    assert!(none.is_empty());
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn retain_secure_wipes_vacated_tail() {
+    let mut data = Dynamic::<Vec<u8>>::new(vec![0xAA, 0xBB, 0xAA, 0xBB]);
+    data.retain_secure(|b| b == 0xAA);
+    assert_eq!(data.expose_secret(), &[0xAA, 0xAA]);
+
+    // Capacity is kept, so the old tail is still inspectable within the allocation
+    let v = data.expose_secret();
+    assert!(v.capacity() >= 4);
+    let tail = unsafe { std::slice::from_raw_parts(v.as_ptr().add(2), 2) };
+    assert_eq!(tail, &[0, 0]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// verify_candidate() — constant-time check, candidate consumed
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "ct-eq")]
+#[test]
+fn verify_candidate_string() {
+    let stored: Dynamic<String> = "reset-token".into();
+    assert!(stored.verify_candidate("reset-token".into()));
+    assert!(!stored.verify_candidate("reset-tokeN".into()));
+    assert!(!stored.verify_candidate("reset".into()));
+    assert!(!stored.verify_candidate("reset-token-extra".into()));
+    assert!(!stored.verify_candidate(String::new().into()));
+}
+
+#[cfg(feature = "ct-eq")]
+#[test]
+fn verify_candidate_bytes() {
+    let stored = Dynamic::<Vec<u8>>::new(vec![0xDE, 0xAD, 0xBE, 0xEF]);
+    assert!(stored.verify_candidate(Dynamic::new(vec![0xDE, 0xAD, 0xBE, 0xEF])));
+    assert!(!stored.verify_candidate(Dynamic::new(vec![0xDE, 0xAD, 0xBE, 0xEE])));
+    assert!(!stored.verify_candidate(Dynamic::new(vec![0xDE, 0xAD])));
+
+    let empty = Dynamic::<Vec<u8>>::new(Vec::new());
+    assert!(empty.verify_candidate(Dynamic::new(Vec::new())));
+}
+
+// ──────────────────────────────────────────────────────────────
+// rotate_left() / rotate_right() — bounds-checked rotation
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn rotate_left_and_right_round_trip() {
+    let mut data = Dynamic::<Vec<u8>>::new(vec![1, 2, 3, 4, 5]);
+    data.rotate_left(2).unwrap();
+    assert_eq!(data.expose_secret(), &[3, 4, 5, 1, 2]);
+    data.rotate_right(2).unwrap();
+    assert_eq!(data.expose_secret(), &[1, 2, 3, 4, 5]);
+
+    // mid == len is a full rotation, not an error
+    data.rotate_left(5).unwrap();
+    assert_eq!(data.expose_secret(), &[1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn rotate_out_of_bounds_errors_without_change() {
+    let mut data = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    assert_eq!(
+        data.rotate_left(4),
+        Err(SecureGateError::OutOfBounds { index: 4, len: 3 })
+    );
+    assert_eq!(
+        data.rotate_right(usize::MAX),
+        Err(SecureGateError::OutOfBounds {
+            index: usize::MAX,
+            len: 3
+        })
+    );
+    assert_eq!(data.expose_secret(), &[1, 2, 3]);
+
+    let mut empty = Dynamic::<Vec<u8>>::new(Vec::new());
+    assert!(empty.rotate_left(0).is_ok());
+}
+
+// ──────────────────────────────────────────────────────────────
+// from_iter_exact() — single preallocation
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn from_iter_exact_fills_with_one_allocation() {
+    let secret = Dynamic::<Vec<u8>>::from_iter_exact(64, (0..64u8).map(|i| i ^ 0x5A));
+    assert_eq!(secret.len(), 64);
+    assert_eq!(secret.expose_secret().capacity(), 64);
+    assert_eq!(secret.expose_secret()[1], 1 ^ 0x5A);
+
+    let empty = Dynamic::<Vec<u8>>::from_iter_exact(0, core::iter::empty());
+    assert!(empty.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "did not yield exactly 4 items")]
+fn from_iter_exact_too_short_panics() {
+    let _ = Dynamic::<Vec<u8>>::from_iter_exact(4, [1u8, 2, 3].into_iter());
+}
+
+#[test]
+#[should_panic(expected = "did not yield exactly 2 items")]
+fn from_iter_exact_too_long_panics() {
+    let _ = Dynamic::<Vec<u8>>::from_iter_exact(2, [1u8, 2, 3].into_iter());
+}
+
+// ──────────────────────────────────────────────────────────────
+// make_ascii_lowercase() / make_ascii_uppercase() — in-place case folding
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn make_ascii_case_in_place() {
+    let mut phrase: Dynamic<String> = "Héllo WORLD 42".into();
+    let ptr = phrase.expose_secret().as_ptr();
+
+    phrase.make_ascii_lowercase();
+    assert_eq!(phrase.expose_secret(), "héllo world 42");
+    phrase.make_ascii_uppercase();
+    assert_eq!(phrase.expose_secret(), "HéLLO WORLD 42");
+
+    // Same buffer throughout — no copies
+    assert_eq!(phrase.expose_secret().as_ptr(), ptr);
+}
+
+// ──────────────────────────────────────────────────────────────
+// parse_secret() — typed parsing that stays wrapped
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn parse_secret_wraps_value() {
+    let pin: Dynamic<String> = "0042".into();
+    let parsed = pin.parse_secret::<u32>().unwrap();
+    assert_eq!(*parsed.expose_secret(), 42);
+    assert_eq!(format!("{parsed:?}"), "[REDACTED]");
+
+    let bad: Dynamic<String> = "12a4".into();
+    assert!(bad.parse_secret::<u32>().is_err());
+    assert!(Dynamic::<String>::from("").parse_secret::<u64>().is_err());
+}
+
+// ──────────────────────────────────────────────────────────────
+// from_utf8_lossy() — tolerant ingestion
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn from_utf8_lossy_keeps_valid_and_replaces_invalid() {
+    let exact = Dynamic::<String>::from_utf8_lossy("héllo".as_bytes());
+    assert_eq!(exact.expose_secret(), "héllo");
+
+    let lossy = Dynamic::<String>::from_utf8_lossy(&[b'a', 0xC3, b'b', 0xFF]);
+    assert_eq!(lossy.expose_secret(), "a\u{FFFD}b\u{FFFD}");
+    assert_eq!(format!("{lossy:?}"), "[REDACTED]");
+}
+
+#[cfg(feature = "ct-eq")]
+#[test]
+fn make_ascii_lowercase_before_ct_compare() {
+    let stored: Dynamic<String> = "recovery phrase".into();
+    let mut given: Dynamic<String> = "Recovery PHRASE".into();
+    given.make_ascii_lowercase();
+    assert!(stored.verify_candidate(given));
+}
+
+// ──────────────────────────────────────────────────────────────
+// zeroize_range() — selective wiping
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn zeroize_range_wipes_only_the_range() {
+    let mut buf = Dynamic::<Vec<u8>>::new(vec![0xFF; 8]);
+    buf.zeroize_range(2..5).unwrap();
+    assert_eq!(buf.expose_secret(), &[0xFF, 0xFF, 0, 0, 0, 0xFF, 0xFF, 0xFF]);
+
+    // Empty and full ranges
+    buf.zeroize_range(8..8).unwrap();
+    assert_eq!(buf.len(), 8);
+    buf.zeroize_range(0..8).unwrap();
+    assert_eq!(buf.expose_secret(), &[0u8; 8]);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn zeroize_range_out_of_bounds_wipes_nothing() {
+    let mut buf = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    assert_eq!(
+        buf.zeroize_range(1..4),
+        Err(SecureGateError::OutOfBounds { index: 4, len: 3 })
+    );
+    assert_eq!(
+        buf.zeroize_range(2..1),
+        Err(SecureGateError::OutOfBounds { index: 2, len: 3 })
+    );
+    assert_eq!(buf.expose_secret(), &[1, 2, 3]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// redacted_summary() — type and length, never content
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_redacted_summary() {
+    let blob = Dynamic::<Vec<u8>>::new(vec![0xAA; 32]);
+    assert_eq!(blob.redacted_summary(), "Dynamic<Vec<u8>>(len=32)");
+
+    let pw: Dynamic<String> = "hunter2".into();
+    let summary = pw.redacted_summary();
+    assert_eq!(summary, "Dynamic<String>(len=7)");
+    assert!(!summary.contains("hunter2"));
+    assert_eq!(format!("{pw:?}"), "[REDACTED]");
+}
+
+#[cfg(feature = "debug-len")]
+#[test]
+fn dynamic_redacted_debug_with_len() {
+    let pw: Dynamic<String> = "hunter22".into();
+    assert_eq!(format!("{:?}", pw.redacted_debug_with_len()), "[REDACTED len=8]");
+    assert_eq!(format!("{pw:?}"), "[REDACTED]");
+
+    let blob = Dynamic::<Vec<u8>>::new(vec![0u8; 64]);
+    assert_eq!(format!("{:?}", blob.redacted_debug_with_len()), "[REDACTED len=64]");
+}
+
+// ──────────────────────────────────────────────────────────────
+// as_str_checked() — zero-copy UTF-8 view
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_as_str_checked_borrows_buffer() {
+    let blob = Dynamic::<Vec<u8>>::new("pässwörd".as_bytes().to_vec());
+    let view = blob.as_str_checked().unwrap();
+    assert_eq!(view, "pässwörd");
+    assert_eq!(view.as_ptr(), blob.expose_secret().as_ptr());
+
+    let bad = Dynamic::<Vec<u8>>::new(vec![b'a', 0xC3]); // truncated sequence
+    let err = bad.as_str_checked().unwrap_err();
+    assert_eq!(err.valid_up_to(), 1);
+    assert_eq!(err.error_len(), None);
+    assert_eq!(Dynamic::<Vec<u8>>::new(Vec::new()).as_str_checked().unwrap(), "");
+}
+
+// ──────────────────────────────────────────────────────────────
+// into_boxed_slice_unmanaged() — FFI escape hatch
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_into_boxed_slice_unmanaged_exact_length() {
+    let key = Dynamic::<Vec<u8>>::new(vec![1u8, 2, 3]);
+    let raw: Box<[u8]> = key.into_boxed_slice_unmanaged();
+    assert_eq!(&*raw, &[1, 2, 3]);
+
+    let mut spare = Vec::with_capacity(64);
+    spare.extend_from_slice(&[9u8; 5]);
+    let raw = Dynamic::<Vec<u8>>::new(spare).into_boxed_slice_unmanaged();
+    assert_eq!(raw.len(), 5);
+    assert_eq!(&*raw, &[9u8; 5]);
+
+    assert!(Dynamic::<Vec<u8>>::new(Vec::new())
+        .into_boxed_slice_unmanaged()
+        .is_empty());
+}
@@ -0,0 +1,53 @@
+// ==========================================================================
+// tests/error_tests.rs
+// ==========================================================================
+// SecureGateError: Display messages and std::error::Error integration
+
+use secure_gate::SecureGateError;
+
+#[test]
+fn error_display_messages() {
+    assert_eq!(
+        SecureGateError::InvalidHex.to_string(),
+        "invalid hex string"
+    );
+    assert_eq!(
+        SecureGateError::InvalidBase64.to_string(),
+        "invalid base64url string"
+    );
+    assert_eq!(
+        SecureGateError::LengthMismatch {
+            expected: 32,
+            got: 31
+        }
+        .to_string(),
+        "length mismatch: expected 32 bytes, got 31"
+    );
+    assert_eq!(
+        SecureGateError::ChunkMisaligned {
+            chunk_size: 16,
+            len: 20
+        }
+        .to_string(),
+        "length 20 is not a multiple of the chunk size 16"
+    );
+    assert_eq!(
+        SecureGateError::OutOfBounds { index: 5, len: 4 }.to_string(),
+        "index 5 is out of bounds for length 4"
+    );
+    assert_eq!(
+        SecureGateError::InvalidBincode.to_string(),
+        "invalid bincode data"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn error_is_std_error_and_boxes() {
+    fn fails() -> Result<(), Box<dyn std::error::Error>> {
+        Err(SecureGateError::RngFailure)?
+    }
+    let err = fails().unwrap_err();
+    assert_eq!(err.to_string(), "random number generator failure");
+    assert!(err.downcast_ref::<SecureGateError>().is_some());
+}
@@ -0,0 +1,122 @@
+// ==========================================================================
+// tests/expose_tests.rs
+// ==========================================================================
+// Typed exposure views: `expose()` / `expose_mut()`
+
+use secure_gate::{Dynamic, Fixed};
+
+// ──────────────────────────────────────────────────────────────
+// Fixed byte arrays
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_expose_as_slice_and_array() {
+    let key = Fixed::new([1u8, 2, 3, 4]);
+    let view = key.expose();
+    assert_eq!(view.as_slice(), &[1, 2, 3, 4]);
+    assert_eq!(view.as_array(), &[1, 2, 3, 4]);
+    assert_eq!(view.as_ref(), &[1, 2, 3, 4]);
+}
+
+#[test]
+fn fixed_expose_mut_writes_through() {
+    let mut key = Fixed::new([0u8; 4]);
+    key.expose_mut().as_mut_slice()[0] = 0xAA;
+    key.expose_mut().as_mut_array()[3] = 0xBB;
+    assert_eq!(key.expose_secret(), &[0xAA, 0, 0, 0xBB]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// Stack and heap give the same entry point
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_and_dynamic_share_as_slice() {
+    let stack = Fixed::new([9u8; 8]);
+    let heap = Dynamic::<Vec<u8>>::new(vec![9u8; 8]);
+    assert_eq!(stack.expose().as_slice(), heap.expose().as_slice());
+
+    let pw: Dynamic<String> = "hunter2".into();
+    assert_eq!(pw.expose().as_str(), "hunter2");
+    assert_eq!(pw.expose().as_slice(), b"hunter2");
+}
+
+#[test]
+fn expose_views_are_redacted() {
+    let mut key = Fixed::new([1u8; 4]);
+    assert_eq!(format!("{:?}", key.expose()), "[REDACTED]");
+    assert_eq!(format!("{:?}", key.expose_mut()), "[REDACTED]");
+}
+
+// ──────────────────────────────────────────────────────────────
+// Self-wiping scratch views (zeroize)
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn expose_scratch_wipes_on_drop() {
+    let mut buf = Dynamic::<Vec<u8>>::new(vec![1u8; 24]);
+    {
+        let mut scratch = buf.expose_scratch();
+        assert_eq!(&scratch[..], &[1u8; 24]);
+        scratch[0] = 0xFF;
+        assert_eq!(format!("{scratch:?}"), "[REDACTED]");
+    }
+    assert_eq!(buf.len(), 24);
+    assert_eq!(buf.expose_secret(), &[0u8; 24]);
+}
+
+#[cfg(all(feature = "zeroize", feature = "std"))]
+#[test]
+fn expose_scratch_wipes_on_panic() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let mut buf = Dynamic::<Vec<u8>>::new(vec![0u8; 8]);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let mut scratch = buf.expose_scratch();
+        scratch.fill(0xAB);
+        panic!("abort mid-computation");
+    }));
+    assert!(result.is_err());
+    assert_eq!(buf.expose_secret(), &[0u8; 8]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// Streaming reads (std)
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "std")]
+#[test]
+fn reader_streams_all_bytes_via_io_copy() {
+    let secret = Dynamic::<Vec<u8>>::new((0..=255u8).cycle().take(10_000).collect::<Vec<u8>>());
+    let mut sink = Vec::new();
+
+    let copied = std::io::copy(&mut secret.reader(), &mut sink).unwrap();
+
+    assert_eq!(copied, 10_000);
+    assert_eq!(&sink, secret.expose_secret());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn reader_tracks_position_and_hits_eof() {
+    use std::io::Read;
+
+    let secret = Dynamic::<Vec<u8>>::new(vec![1, 2, 3, 4, 5]);
+    let mut reader = secret.reader();
+    assert_eq!(format!("{reader:?}"), "[REDACTED]");
+
+    let mut buf = [0u8; 2];
+    assert_eq!(reader.read(&mut buf).unwrap(), 2);
+    assert_eq!(buf, [1, 2]);
+    assert_eq!(reader.read(&mut buf).unwrap(), 2);
+    assert_eq!(buf, [3, 4]);
+    assert_eq!(reader.read(&mut buf).unwrap(), 1);
+    assert_eq!(buf[0], 5);
+    assert_eq!(reader.read(&mut buf).unwrap(), 0);
+
+    // A fresh reader starts from the beginning; the secret is untouched
+    let mut rest = Vec::new();
+    secret.reader().read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, [1, 2, 3, 4, 5]);
+}
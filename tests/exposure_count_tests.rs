@@ -0,0 +1,95 @@
+// ==========================================================================
+// tests/exposure_count_tests.rs
+// ==========================================================================
+// Debug-build exposure counting (count-exposures feature)
+//
+// Counts are keyed by address and never cleared, so every check compares two
+// readings of the same secret instead of asserting an absolute value.
+
+#![cfg(all(feature = "count-exposures", debug_assertions))]
+
+use secure_gate::{Dynamic, DynamicNoClone, Fixed, FixedNoClone};
+
+#[test]
+fn fixed_counts_reads_and_writes() {
+    let mut key = Fixed::new([0u8; 32]);
+    let before = key.exposure_count();
+
+    for _ in 0..10 {
+        let _ = key.expose().as_slice()[0];
+    }
+    key.expose_secret_mut()[0] = 1;
+    assert_eq!(key.exposure_count() - before, 11);
+}
+
+#[test]
+fn dynamic_counts_reads_and_writes() {
+    let mut pw: Dynamic<String> = "hunter2".into();
+    let before = pw.exposure_count();
+    let _ = pw.expose();
+    pw.expose_secret_mut().push('!');
+    assert_eq!(pw.exposure_count() - before, 2);
+}
+
+#[test]
+fn expose_secret_is_not_counted() {
+    const KEY: Fixed<[u8; 4]> = Fixed::new([1, 2, 3, 4]);
+    const FIRST: u8 = KEY.expose_secret()[0];
+    assert_eq!(FIRST, 1);
+
+    let key = Fixed::new([0u8; 32]);
+    let before = key.exposure_count();
+    let _ = key.expose_secret();
+    assert_eq!(key.exposure_count(), before);
+}
+
+#[test]
+fn metadata_does_not_count() {
+    let key = Fixed::new([0u8; 32]);
+    let pw: Dynamic<Vec<u8>> = vec![1, 2, 3].into();
+    let (key_before, pw_before) = (key.exposure_count(), pw.exposure_count());
+    let _ = key.len();
+    let _ = pw.len();
+    assert_eq!(key.exposure_count(), key_before);
+    assert_eq!(pw.exposure_count(), pw_before);
+}
+
+#[test]
+fn dynamic_count_follows_moves() {
+    let mut pw: Dynamic<Vec<u8>> = vec![1, 2, 3].into();
+    let before = pw.exposure_count();
+    pw.expose_secret_mut()[0] = 9;
+    let moved = pw;
+    assert_eq!(moved.exposure_count() - before, 1);
+}
+
+#[test]
+fn clone_counts_separately() {
+    let key = Fixed::new([0u8; 32]);
+    let copy = key.clone();
+    let (key_before, copy_before) = (key.exposure_count(), copy.exposure_count());
+    let _ = key.expose();
+    assert_eq!(key.exposure_count() - key_before, 1);
+    assert_eq!(copy.exposure_count(), copy_before);
+}
+
+#[test]
+fn no_clone_types_count() {
+    let mut key = FixedNoClone::new([0u8; 16]);
+    let mut pw = DynamicNoClone::new(Box::new("secret".to_string()));
+    let (key_before, pw_before) = (key.exposure_count(), pw.exposure_count());
+    key.expose_secret_mut()[0] = 1;
+    key.expose_secret_mut()[1] = 2;
+    pw.expose_secret_mut().push('!');
+    assert_eq!(key.exposure_count() - key_before, 2);
+    assert_eq!(pw.exposure_count() - pw_before, 1);
+}
+
+#[test]
+fn increment_counts_as_mutable_exposure() {
+    let mut nonce = Fixed::new([0u8; 12]);
+    let before = nonce.exposure_count();
+    nonce.increment_be();
+    nonce.increment_le();
+    assert_eq!(nonce.exposure_count() - before, 2);
+}
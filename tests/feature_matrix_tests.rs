@@ -0,0 +1,33 @@
+// ==========================================================================
+// tests/feature_matrix_tests.rs
+// ==========================================================================
+// Which APIs appear under which feature combinations
+
+#[allow(unused_imports)]
+use secure_gate::{Dynamic, Fixed};
+
+// ──────────────────────────────────────────────────────────────
+// Constant-time equality: `ct-eq`, also implied by `conversions`
+// (`src/lib.rs` refuses to build `conversions` without `ct-eq`)
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "ct-eq")]
+#[test]
+fn ct_eq_available_on_wrappers() {
+    let a = Dynamic::<Vec<u8>>::new(vec![1u8, 2, 3]);
+    let b = Dynamic::<Vec<u8>>::new(vec![1u8, 2, 3]);
+    assert!(a.ct_eq(&b));
+    assert!(Fixed::new([7u8; 4]).ct_eq(&Fixed::new([7u8; 4])));
+    assert!(secure_gate::ct_eq_bytes(b"abc", b"abc"));
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn ct_eq_available_through_conversions() {
+    use secure_gate::SecureConversionsExt;
+
+    let key = Fixed::new([9u8; 8]);
+    assert!(key.expose_secret().ct_eq(&[9u8; 8]));
+    let a = Dynamic::<Vec<u8>>::new(vec![9u8; 8]);
+    assert!(a.ct_eq(&Dynamic::<Vec<u8>>::new(vec![9u8; 8])));
+}
@@ -55,6 +55,23 @@ fn fixed_new_very_large() {
     assert_eq!(key.len(), 4096);
 }
 
+#[test]
+fn fixed_len_associated_consts() {
+    // Size a related buffer from the type alone, as array-generic wrappers do
+    fn scratch_for<const N: usize>() -> Vec<u8> {
+        if Fixed::<[u8; N]>::IS_EMPTY {
+            return Vec::new();
+        }
+        vec![0u8; Fixed::<[u8; N]>::LEN]
+    }
+
+    const KEY_LEN: usize = Fixed::<[u8; 32]>::LEN;
+    let buf = [0u8; KEY_LEN];
+    assert_eq!(buf.len(), Fixed::new([0u8; 32]).len());
+    assert_eq!(scratch_for::<16>().len(), 16);
+    assert!(scratch_for::<0>().is_empty());
+}
+
 // ──────────────────────────────────────────────────────────────
 // Fixed::from_slice() edge cases
 // ──────────────────────────────────────────────────────────────
@@ -420,6 +437,32 @@ fn fixed_ct_eq_different_sizes() {
     assert!(key32.ct_eq(&Fixed::new([42u8; 32])));
 }
 
+#[cfg(feature = "ct-eq")]
+#[test]
+fn fixed_is_zero_ct_explicit_values() {
+    assert!(Fixed::new([0u8; 32]).is_zero_ct());
+    assert!(Fixed::new([0u8; 0]).is_zero_ct());
+    assert!(!Fixed::new([0xFFu8; 32]).is_zero_ct());
+
+    // A single non-zero bit anywhere must be detected
+    for pos in 0..32 {
+        for bit in 0..8 {
+            let mut key = [0u8; 32];
+            key[pos] = 1 << bit;
+            assert!(!Fixed::new(key).is_zero_ct(), "byte {pos}, bit {bit}");
+        }
+    }
+}
+
+#[cfg(all(feature = "ct-eq", feature = "rand"))]
+#[test]
+fn fixed_is_zero_ct_random_keys() {
+    for _ in 0..100 {
+        let key: Fixed<[u8; 32]> = Fixed::generate_random();
+        assert_eq!(key.is_zero_ct(), *key.expose_secret() == [0u8; 32]);
+    }
+}
+
 // ──────────────────────────────────────────────────────────────
 // generate_random() edge cases (feature-gated)
 // ──────────────────────────────────────────────────────────────
@@ -460,6 +503,18 @@ fn fixed_generate_random_single_byte() {
     assert!(*key.expose_secret() != [0u8]);
 }
 
+#[cfg(feature = "rand")]
+#[test]
+fn fixed_fill_random_overwrites_in_place() {
+    let mut key = Fixed::new([0u8; 32]);
+    key.fill_random();
+    let first = *key.expose_secret();
+    key.fill_random();
+
+    assert_ne!(first, [0u8; 32]);
+    assert_ne!(*key.expose_secret(), first);
+}
+
 #[cfg(feature = "rand")]
 #[test]
 fn fixed_generate_random_multiple_different() {
@@ -556,6 +611,50 @@ fn fixed_zeroize_partial_data() {
     assert_eq!(*key.expose_secret(), [0u8; 5]);
 }
 
+#[cfg(feature = "zeroize")]
+#[derive(zeroize::Zeroize)]
+struct CompositeSecret {
+    enc_key: [u8; 32],
+    mac_key: [u8; 16],
+    context: Vec<u8>,
+}
+
+#[cfg(feature = "zeroize")]
+fn composite() -> Fixed<CompositeSecret> {
+    Fixed::new(CompositeSecret {
+        enc_key: [0xAA; 32],
+        mac_key: [0xBB; 16],
+        context: vec![0xCC; 64],
+    })
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn fixed_zeroize_nested_struct_wipes_every_field() {
+    use zeroize::Zeroize;
+
+    let mut secret = composite();
+    secret.zeroize();
+
+    let inner = secret.expose_secret();
+    assert_eq!(inner.enc_key, [0u8; 32]);
+    assert_eq!(inner.mac_key, [0u8; 16]);
+    // `Vec::zeroize` wipes the contents and then clears it
+    assert!(inner.context.is_empty());
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn fixed_zeroize_now_nested_struct_wipes_every_field() {
+    let mut secret = composite();
+    secret.zeroize_now();
+
+    let inner = secret.expose_secret();
+    assert_eq!(inner.enc_key, [0u8; 32]);
+    assert_eq!(inner.mac_key, [0u8; 16]);
+    assert!(inner.context.is_empty());
+}
+
 // ──────────────────────────────────────────────────────────────
 // no_clone() conversion edge cases
 // ──────────────────────────────────────────────────────────────
@@ -757,17 +856,18 @@ fn fixed_from_hex_mixed_case() {
 #[test]
 fn fixed_from_hex_invalid_char() {
     let err = Fixed::<[u8; 4]>::from_hex("deadgbeef").unwrap_err();
-    assert_eq!(err, "invalid hex string");
+    assert_eq!(err, secure_gate::SecureGateError::InvalidHex);
+    assert_eq!(err.to_string(), "invalid hex string");
 }
 
 #[cfg(feature = "conversions")]
 #[test]
 fn fixed_from_hex_wrong_length() {
     let err = Fixed::<[u8; 4]>::from_hex("deadbe").unwrap_err();
-    assert_eq!(err, "hex string length mismatch");
+    assert_eq!(err, secure_gate::SecureGateError::LengthMismatch { expected: 4, got: 3 });
     
     let err = Fixed::<[u8; 4]>::from_hex("deadbeef00").unwrap_err();
-    assert_eq!(err, "hex string length mismatch");
+    assert_eq!(err, secure_gate::SecureGateError::LengthMismatch { expected: 4, got: 5 });
 }
 
 #[cfg(feature = "conversions")]
@@ -809,14 +909,14 @@ fn fixed_from_base64url_wrong_length() {
     let bytes = [0xde, 0xad, 0xbe, 0xef, 0x00];
     let b64 = URL_SAFE_NO_PAD.encode(bytes);
     let err = Fixed::<[u8; 4]>::from_base64url(&b64).unwrap_err();
-    assert_eq!(err, "base64url string length mismatch");
+    assert_eq!(err, secure_gate::SecureGateError::LengthMismatch { expected: 4, got: 5 });
 }
 
 #[cfg(feature = "conversions")]
 #[test]
 fn fixed_from_base64url_invalid() {
     let err = Fixed::<[u8; 4]>::from_base64url("invalid!").unwrap_err();
-    assert_eq!(err, "invalid base64url string");
+    assert_eq!(err, secure_gate::SecureGateError::InvalidBase64);
 }
 
 #[cfg(feature = "conversions")]
@@ -846,3 +946,161 @@ fn fixed_from_hex_and_base64url_roundtrip() {
     assert_eq!(from_b64.expose_secret(), &original);
 }
 
+
+// ──────────────────────────────────────────────────────────────
+// replace() for key rotation
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_replace_returns_old_secret() {
+    let mut key = Fixed::new([1u8; 32]);
+    let old = key.replace([2u8; 32]);
+
+    assert_eq!(old.expose_secret(), &[1u8; 32]);
+    assert_eq!(key.expose_secret(), &[2u8; 32]);
+    assert_eq!(format!("{old:?}"), "[REDACTED]");
+}
+
+// ──────────────────────────────────────────────────────────────
+// clone_secret() — explicit, grep-able duplication
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_clone_secret_matches_clone() {
+    let mut original = Fixed::new([5u8; 16]);
+    let copy = original.clone_secret();
+    assert_eq!(copy.expose_secret(), original.clone().expose_secret());
+    original.expose_secret_mut()[0] = 0;
+    assert_eq!(copy.expose_secret(), &[5u8; 16]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// copy_out() — explicit copy of Copy inner values
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_copy_out_returns_value() {
+    let counter = Fixed::new(7u64);
+    assert_eq!(counter.copy_out(), 7);
+
+    let key = Fixed::new([3u8; 4]);
+    let mut raw = key.copy_out();
+    raw[0] = 0;
+    assert_eq!(raw, [0, 3, 3, 3]);
+    assert_eq!(key.expose_secret(), &[3u8; 4]); // original untouched
+}
+
+// ──────────────────────────────────────────────────────────────
+// Clone failure: partial clones are dropped (and wiped) on unwind
+// ──────────────────────────────────────────────────────────────
+
+mod clone_failure {
+    use secure_gate::Fixed;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static WIPED: AtomicUsize = AtomicUsize::new(0);
+
+    // Stand-in for a self-wiping field: counts wipes of cloned copies on drop
+    struct WipedOnDrop(Vec<u8>, bool);
+
+    impl Clone for WipedOnDrop {
+        fn clone(&self) -> Self {
+            WipedOnDrop(self.0.clone(), true)
+        }
+    }
+
+    impl Drop for WipedOnDrop {
+        fn drop(&mut self) {
+            self.0.iter_mut().for_each(|b| *b = 0);
+            if self.1 {
+                WIPED.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    }
+
+    struct PanicsOnClone;
+
+    impl Clone for PanicsOnClone {
+        fn clone(&self) -> Self {
+            panic!("clone failed");
+        }
+    }
+
+    #[derive(Clone)]
+    struct Composite {
+        key: WipedOnDrop,
+        _tail: PanicsOnClone,
+    }
+
+    #[test]
+    fn panicking_clone_leaves_source_intact_and_wipes_partial_copy() {
+        let secret = Fixed::new(Composite {
+            key: WipedOnDrop(vec![0xAA; 32], false),
+            _tail: PanicsOnClone,
+        });
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| secret.clone()));
+        assert!(result.is_err());
+
+        // The already-cloned `key` copy was dropped (and wiped) during unwinding
+        assert_eq!(WIPED.load(Ordering::SeqCst), 1);
+        // The source secret is untouched
+        assert_eq!(secret.expose_secret().key.0, vec![0xAA; 32]);
+    }
+}
+
+// ──────────────────────────────────────────────────────────────
+// increment_be() / increment_le() — counter nonces
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_increment_be_carries_and_wraps() {
+    let mut nonce = Fixed::new([0u8; 12]);
+    nonce.increment_be();
+    assert_eq!(nonce.expose_secret()[11], 1);
+
+    let mut carry = Fixed::new([0x01, 0xFF, 0xFF]);
+    carry.increment_be();
+    assert_eq!(carry.expose_secret(), &[0x02, 0x00, 0x00]);
+
+    let mut max = Fixed::new([0xFFu8; 4]);
+    max.increment_be();
+    assert_eq!(max.expose_secret(), &[0u8; 4]);
+}
+
+#[test]
+fn fixed_increment_le_matches_u32() {
+    let mut counter = Fixed::new(0x00FF_FFFEu32.to_le_bytes());
+    for expected in [0x00FF_FFFFu32, 0x0100_0000, 0x0100_0001] {
+        counter.increment_le();
+        assert_eq!(u32::from_le_bytes(*counter.expose_secret()), expected);
+    }
+
+    let mut max = Fixed::new(u32::MAX.to_le_bytes());
+    max.increment_le();
+    assert_eq!(max.expose_secret(), &[0u8; 4]);
+
+    let mut empty = Fixed::new([0u8; 0]);
+    empty.increment_le();
+    empty.increment_be();
+}
+
+// ──────────────────────────────────────────────────────────────
+// redacted_summary() — type and length, never content
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_redacted_summary() {
+    let key = Fixed::new([0xAAu8; 16]);
+    assert_eq!(key.redacted_summary(), "Fixed<[u8; 16]>(len=16)");
+    assert_eq!(Fixed::new([0u8; 0]).redacted_summary(), "Fixed<[u8; 0]>(len=0)");
+    assert_eq!(format!("{key:?}"), "[REDACTED]");
+}
+
+#[cfg(feature = "debug-len")]
+#[test]
+fn fixed_redacted_debug_with_len() {
+    let key = Fixed::new([0u8; 32]);
+    assert_eq!(format!("{:?}", key.redacted_debug_with_len()), "[REDACTED len=32]");
+    assert_eq!(format!("{key:?}"), "[REDACTED]");
+}
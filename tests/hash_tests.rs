@@ -0,0 +1,50 @@
+// ==========================================================================
+// tests/hash_tests.rs
+// ==========================================================================
+// Keyed-digest Hash impls (hash-digest feature)
+
+#![cfg(feature = "hash-digest")]
+
+use secure_gate::{Dynamic, Fixed};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn raw_hash_of(bytes: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn fixed_hash_is_stable_within_process() {
+    let a = Fixed::new([7u8; 32]);
+    let b = Fixed::new([7u8; 32]);
+    assert_eq!(hash_of(&a), hash_of(&b));
+}
+
+#[test]
+fn fixed_hash_differs_for_different_secrets() {
+    let a = Fixed::new([1u8; 32]);
+    let b = Fixed::new([2u8; 32]);
+    assert_ne!(hash_of(&a), hash_of(&b));
+}
+
+#[test]
+fn hash_does_not_feed_raw_bytes() {
+    let key = Fixed::new([0x42u8; 16]);
+    assert_ne!(hash_of(&key), raw_hash_of(&[0x42u8; 16]));
+}
+
+#[test]
+fn dynamic_hash_matches_across_string_and_vec() {
+    let pw: Dynamic<String> = "hunter2".into();
+    let bytes = Dynamic::<Vec<u8>>::new(b"hunter2".to_vec());
+    // Both digest the same byte sequence
+    assert_eq!(hash_of(&pw), hash_of(&bytes));
+}
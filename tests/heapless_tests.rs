@@ -0,0 +1,77 @@
+// ==========================================================================
+// tests/heapless_tests.rs
+// ==========================================================================
+// Allocator-free HeaplessSecret (heapless feature)
+
+#![cfg(feature = "heapless")]
+
+use secure_gate::{HeaplessSecret, SecureGateError};
+
+// ──────────────────────────────────────────────────────────────
+// Construction and access
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn heapless_basic_access() {
+    let mut secret = HeaplessSecret::<8>::new();
+    assert!(secret.is_empty());
+    assert_eq!(secret.capacity(), 8);
+
+    secret
+        .expose_secret_mut()
+        .extend_from_slice(b"abc")
+        .unwrap();
+    assert_eq!(secret.len(), 3);
+    assert_eq!(secret.expose_secret().as_slice(), b"abc");
+}
+
+#[test]
+fn heapless_from_slice_respects_capacity() {
+    assert!(HeaplessSecret::<4>::from_slice(&[1, 2, 3, 4]).is_ok());
+    assert_eq!(
+        HeaplessSecret::<4>::from_slice(&[0u8; 5]).unwrap_err(),
+        SecureGateError::CapacityExceeded {
+            capacity: 4,
+            got: 5
+        }
+    );
+}
+
+#[test]
+fn heapless_push_beyond_capacity_fails() {
+    let mut secret = HeaplessSecret::<2>::from_slice(&[1, 2]).unwrap();
+    assert!(secret.expose_secret_mut().push(3).is_err());
+    assert_eq!(secret.len(), 2);
+}
+
+#[test]
+fn heapless_debug_is_redacted() {
+    let secret = HeaplessSecret::<16>::from_slice(b"hunter2").unwrap();
+    assert_eq!(format!("{secret:?}"), "[REDACTED]");
+    assert_eq!(
+        format!("{:?}", HeaplessSecret::<4>::default()),
+        "[REDACTED]"
+    );
+}
+
+// ──────────────────────────────────────────────────────────────
+// Zeroization (zeroize feature)
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn heapless_zeroize_clears_and_stays_usable() {
+    use zeroize::Zeroize;
+
+    let mut secret = HeaplessSecret::<8>::from_slice(&[0xAA; 8]).unwrap();
+    secret.expose_secret_mut().truncate(4);
+    secret.zeroize();
+    assert!(secret.is_empty());
+    assert_eq!(secret.capacity(), 8);
+
+    secret
+        .expose_secret_mut()
+        .extend_from_slice(&[1, 2])
+        .unwrap();
+    assert_eq!(secret.expose_secret().as_slice(), &[1, 2]);
+}
@@ -0,0 +1,52 @@
+// ==========================================================================
+// tests/hkdf_tests.rs
+// ==========================================================================
+// HKDF-SHA256 subkey derivation from a wrapped master key
+
+#![cfg(feature = "hkdf")]
+
+use secure_gate::Fixed;
+
+// RFC 5869, test case 1 (PRK, info, L = 42, OKM)
+const PRK: [u8; 32] = [
+    0x07, 0x77, 0x09, 0x36, 0x2c, 0x2e, 0x32, 0xdf, 0x0d, 0xdc, 0x3f, 0x0d, 0xc4, 0x7b, 0xba, 0x63,
+    0x90, 0xb6, 0xc7, 0x3b, 0xb5, 0x0f, 0x9c, 0x31, 0x22, 0xec, 0x84, 0x4a, 0xd7, 0xc2, 0xb3, 0xe5,
+];
+const INFO: [u8; 10] = [0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9];
+const OKM: [u8; 42] = [
+    0x3c, 0xb2, 0x5f, 0x25, 0xfa, 0xac, 0xd5, 0x7a, 0x90, 0x43, 0x4f, 0x64, 0xd0, 0x36, 0x2f, 0x2a,
+    0x2d, 0x2d, 0x0a, 0x90, 0xcf, 0x1a, 0x5a, 0x4c, 0x5d, 0xb0, 0x2d, 0x56, 0xec, 0xc4, 0xc5, 0xbf,
+    0x34, 0x00, 0x72, 0x08, 0xd5, 0xb8, 0x87, 0x18, 0x58, 0x65,
+];
+
+#[test]
+fn hkdf_expand_matches_rfc5869_vector() {
+    let master = Fixed::new(PRK);
+    let okm: Fixed<[u8; 42]> = master.hkdf_expand(&INFO);
+    assert_eq!(*okm.expose_secret(), OKM);
+}
+
+#[test]
+fn hkdf_expand_labels_give_independent_subkeys() {
+    let master = Fixed::new([0x42u8; 32]);
+    let enc: Fixed<[u8; 32]> = master.hkdf_expand(b"enc");
+    let mac: Fixed<[u8; 32]> = master.hkdf_expand(b"mac");
+    let enc_again: Fixed<[u8; 32]> = master.hkdf_expand(b"enc");
+
+    assert_ne!(enc.expose_secret(), mac.expose_secret());
+    assert_eq!(enc.expose_secret(), enc_again.expose_secret());
+}
+
+#[test]
+fn hkdf_expand_shorter_output_is_prefix() {
+    let master = Fixed::new(PRK);
+    let short: Fixed<[u8; 16]> = master.hkdf_expand(&INFO);
+    assert_eq!(short.expose_secret()[..], OKM[..16]);
+}
+
+#[test]
+#[should_panic(expected = "L exceeds 255 * 32 bytes")]
+fn hkdf_expand_rejects_oversized_output() {
+    let master = Fixed::new(PRK);
+    let _: Fixed<[u8; 8161]> = master.hkdf_expand(b"too long");
+}
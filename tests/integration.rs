@@ -22,6 +22,25 @@ fn basic_usage_explicit_access() {
     assert_eq!(key.expose_secret()[0], 1); // ← fixed: proper assert_eq!
 }
 
+#[test]
+fn single_byte_access_without_index() {
+    let mut key = Fixed::new([0u8; 32]);
+
+    key.set_byte(0, 1);
+    key.set_byte(31, 0xff);
+
+    assert_eq!(key.get_byte(0), 1);
+    assert_eq!(key.get_byte(31), 0xff);
+    assert_eq!(key.expose_secret()[1..31], [0u8; 30]);
+}
+
+#[test]
+#[should_panic]
+fn set_byte_out_of_bounds_panics() {
+    let mut key = Fixed::new([0u8; 4]);
+    key.set_byte(4, 1);
+}
+
 #[test]
 fn fixed_is_truly_zero_cost() {
     let key = Fixed::new([0u8; 32]);
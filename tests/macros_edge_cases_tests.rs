@@ -90,15 +90,15 @@ fn random_hex_via_alias() {
 #[cfg(feature = "conversions")]
 #[test]
 fn hexstring_new_rejects_invalid() {
-    use secure_gate::HexString;
+    use secure_gate::{HexString, SecureGateError};
 
     let s = "invalid hex".to_string(); // odd length
     let err = HexString::new(s).unwrap_err();
-    assert_eq!(err, "invalid hex string");
+    assert_eq!(err, SecureGateError::InvalidHex);
 
     let s = "g".to_string(); // invalid digit
     let err = HexString::new(s).unwrap_err();
-    assert_eq!(err, "invalid hex string");
+    assert_eq!(err, SecureGateError::InvalidHex);
 }
 
 #[cfg(feature = "conversions")]
@@ -90,15 +90,15 @@ fn random_hex_via_alias() {
 #[cfg(feature = "conversions")]
 #[test]
 fn hexstring_new_rejects_invalid() {
-    use secure_gate::HexString;
+    use secure_gate::{HexString, SecureGateError};
 
     let s = "invalid hex".to_string(); // odd length
     let err = HexString::new(s).unwrap_err();
-    assert_eq!(err, "invalid hex string");
+    assert_eq!(err, SecureGateError::InvalidHex);
 
     let s = "g".to_string(); // invalid digit
     let err = HexString::new(s).unwrap_err();
-    assert_eq!(err, "invalid hex string");
+    assert_eq!(err, SecureGateError::InvalidHex);
 }
 
 #[cfg(feature = "conversions")]
@@ -241,6 +241,25 @@ fn fixed_alias_large_size() {
     assert_eq!(k.expose_secret().len(), 4096);
 }
 
+#[test]
+fn fixed_alias_at_max_size_compiles() {
+    // Only the alias is declared — nothing is placed on the stack
+    fixed_alias!(MaxKey, 1048576);
+    assert_eq!(core::mem::size_of::<MaxKey>(), secure_gate::MAX_FIXED_SIZE);
+}
+
+#[test]
+fn fixed_alias_custom_max() {
+    const LIMIT: usize = 64;
+    fixed_alias!(pub(crate) BoundedKey, 64, max = LIMIT);
+    fixed_alias!(SmallKey, 16, max = 32);
+
+    let k: BoundedKey = [1u8; 64].into();
+    assert_eq!(k.len(), 64);
+    let s: SmallKey = [2u8; 16].into();
+    assert_eq!(s.len(), 16);
+}
+
 // ──────────────────────────────────────────────────────────────
 // Edge case: Different visibility modifiers
 // ──────────────────────────────────────────────────────────────
@@ -664,3 +683,44 @@ fn fixed_generic_alias_multiple_sizes() {
     assert_eq!(k128.len(), 128);
 }
 
+
+// ──────────────────────────────────────────────────────────────
+// secure_format! — format! into a Dynamic<String>
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn secure_format_builds_wrapped_string() {
+    use secure_gate::{secure_format, Dynamic, Fixed};
+
+    let pin = Fixed::new(1234u32);
+    let token: Dynamic<String> = "t0k".into();
+    let version = 1;
+    let out: Dynamic<String> = secure_format!(
+        "v{version}-{:06}-{}",
+        pin.expose_secret(),
+        token.expose_secret()
+    );
+    assert_eq!(out.expose_secret(), "v1-001234-t0k");
+    assert_eq!(format!("{out:?}"), "[REDACTED]");
+
+    // Passing a wrapper itself only ever yields its redacted Debug
+    let leaked = secure_format!("{token:?}");
+    assert_eq!(leaked.expose_secret(), "[REDACTED]");
+
+    // Large outputs grow the buffer correctly
+    let long = secure_format!("{}", "x".repeat(1000));
+    assert_eq!(long.len(), 1000);
+}
+
+#[test]
+fn dynamic_string_implements_fmt_write() {
+    use core::fmt::Write;
+    use secure_gate::Dynamic;
+
+    let mut s: Dynamic<String> = "a".into();
+    let n = 42;
+    write!(s, "b{n}").unwrap();
+    s.write_str(&"é".repeat(64)).unwrap();
+    assert!(s.expose_secret().starts_with("ab42é"));
+    assert_eq!(s.expose_secret().chars().count(), 4 + 64);
+}
@@ -788,3 +788,76 @@ fn dynamic_no_clone_vec_max_reasonable_size() {
     assert_eq!(data.expose_secret()[0], 42);
     assert_eq!(data.expose_secret()[1023], 42);
 }
+
+// ──────────────────────────────────────────────────────────────
+// capacity() metadata
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_no_clone_capacity_tracks_footprint() {
+    let mut data = DynamicNoClone::new(Box::new(Vec::<u8>::with_capacity(64)));
+    assert!(data.capacity() >= 64);
+    data.expose_secret_mut().extend_from_slice(&[1, 2, 3]);
+    data.expose_secret_mut().shrink_to_fit();
+    assert!(data.capacity() >= 3 && data.capacity() < 64);
+
+    let pw = DynamicNoClone::new(Box::new(String::with_capacity(32)));
+    assert!(pw.capacity() >= 32);
+    assert!(pw.is_empty());
+}
+
+// ──────────────────────────────────────────────────────────────
+// FixedNoClone constant-time equality
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "ct-eq")]
+#[test]
+fn fixed_no_clone_ct_eq() {
+    let a = FixedNoClone::new([7u8; 32]);
+    let b = FixedNoClone::new([7u8; 32]);
+    let mut c_bytes = [7u8; 32];
+    c_bytes[31] = 8;
+    let c = FixedNoClone::new(c_bytes);
+
+    assert!(a.ct_eq(&b));
+    assert!(!a.ct_eq(&c));
+    assert!(FixedNoClone::new([0u8; 0]).ct_eq(&FixedNoClone::new([])));
+}
+
+#[cfg(feature = "ct-eq")]
+#[test]
+fn fixed_no_clone_ct_eq_slice() {
+    let tag = FixedNoClone::new([1u8, 2, 3, 4]);
+    assert!(tag.ct_eq_slice(&[1, 2, 3, 4]));
+    assert!(!tag.ct_eq_slice(&[1, 2, 3, 5]));
+    assert!(!tag.ct_eq_slice(&[1, 2, 3]));
+    assert!(!tag.ct_eq_slice(&[1, 2, 3, 4, 5]));
+    assert!(!tag.ct_eq_slice(&[]));
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn no_clone_fill_random_in_place() {
+    let mut key = FixedNoClone::new([0u8; 32]);
+    key.fill_random();
+    let first = *key.expose_secret();
+    key.fill_random();
+    assert_ne!(first, [0u8; 32]);
+    assert_ne!(*key.expose_secret(), first);
+
+    let mut blob = DynamicNoClone::new(Box::new(vec![0u8; 64]));
+    let ptr = blob.expose_secret().as_ptr();
+    blob.fill_random();
+    assert_eq!(blob.len(), 64);
+    assert_eq!(blob.expose_secret().as_ptr(), ptr);
+    assert!(blob.expose_secret().iter().any(|&b| b != 0));
+}
+
+#[test]
+fn fixed_no_clone_from_array() {
+    let key: FixedNoClone<[u8; 32]> = [9u8; 32].into();
+    assert_eq!(key.expose_secret(), &[9u8; 32]);
+
+    let empty = FixedNoClone::<[u8; 0]>::from([]);
+    assert_eq!(empty.expose_secret().len(), 0);
+}
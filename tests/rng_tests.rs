@@ -7,8 +7,8 @@
 
 use secure_gate::{
     fixed_alias_rng,
-    rng::{DynamicRng, FixedRng},
-    Dynamic, Fixed,
+    rng::{generate_u32_array, generate_u64_array, DynamicRng, FixedRng, RngConfig, RngSource},
+    Dynamic, DynamicNoClone, Fixed, FixedNoClone,
 };
 
 // ──────────────────────────────────────────────────────────────
@@ -146,6 +146,20 @@ fn fixed_rng_very_large() {
     assert!(!rng.expose_secret().iter().all(|&b| b == 0));
 }
 
+#[test]
+fn fixed_rng_size_guard_boundary_is_accepted() {
+    // N == MAX_FIXED_SIZE is the largest size that compiles; one more is a
+    // compile error (see the compile_fail doctest on `FixedRng::generate`).
+    // Run on a thread with a generous stack so the 1 MiB array fits.
+    let len = std::thread::Builder::new()
+        .stack_size(16 * 1024 * 1024)
+        .spawn(|| FixedRng::<{ secure_gate::MAX_FIXED_SIZE }>::generate().len())
+        .unwrap()
+        .join()
+        .unwrap();
+    assert_eq!(len, secure_gate::MAX_FIXED_SIZE);
+}
+
 // ──────────────────────────────────────────────────────────────
 // FixedRng edge cases: Conversions
 // ──────────────────────────────────────────────────────────────
@@ -194,6 +208,20 @@ fn fixed_rng_into_trait_different_sizes() {
     assert_eq!(fixed32.len(), 32);
 }
 
+#[test]
+fn fixed_rng_into_no_clone() {
+    let key: FixedNoClone<[u8; 32]> = FixedRng::<32>::generate().into();
+    assert_eq!(key.expose_secret().len(), 32);
+    assert!(!key.expose_secret().iter().all(|&b| b == 0));
+}
+
+#[test]
+fn dynamic_rng_into_no_clone() {
+    let random: DynamicNoClone<Vec<u8>> = DynamicRng::generate(64).into();
+    assert_eq!(random.len(), 64);
+    assert_eq!(format!("{random:?}"), "[REDACTED]");
+}
+
 // ──────────────────────────────────────────────────────────────
 // FixedRng edge cases: Randomness verification
 // ──────────────────────────────────────────────────────────────
@@ -348,6 +376,28 @@ fn dynamic_rng_into_trait_different_sizes() {
     assert_eq!(dyn32.len(), 32);
 }
 
+#[test]
+fn dynamic_rng_try_into_array_matching_length() {
+    let rng = DynamicRng::generate(32);
+    let expected = rng.expose_secret().to_vec();
+    let arr: [u8; 32] = rng.try_into_array().unwrap();
+    assert_eq!(arr.as_slice(), expected.as_slice());
+}
+
+#[test]
+fn dynamic_rng_try_into_array_mismatch_returns_self() {
+    let rng = DynamicRng::generate(16);
+    let expected = rng.expose_secret().to_vec();
+
+    let back = rng.try_into_array::<32>().unwrap_err();
+    assert_eq!(back.expose_secret(), expected.as_slice());
+    let back = back.try_into_array::<8>().unwrap_err();
+    assert_eq!(back.len(), 16);
+
+    let empty: [u8; 0] = DynamicRng::generate(0).try_into_array().unwrap();
+    assert!(empty.is_empty());
+}
+
 // ──────────────────────────────────────────────────────────────
 // DynamicRng edge cases: Randomness verification
 // ──────────────────────────────────────────────────────────────
@@ -652,3 +702,206 @@ fn dynamic_rng_zeroize_on_drop() {
     assert_eq!(original_len, 64);
 }
 
+
+// ──────────────────────────────────────────────────────────────
+// DynamicRng::generate_alphanumeric()
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn alphanumeric_has_requested_length_and_charset() {
+    for len in [0usize, 1, 24, 100, 1000] {
+        let token = DynamicRng::generate_alphanumeric(len);
+        assert_eq!(token.len(), len);
+        assert!(token
+            .expose_secret()
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric()));
+    }
+}
+
+#[test]
+fn alphanumeric_covers_whole_alphabet() {
+    let token = DynamicRng::generate_alphanumeric(10_000);
+    let s = token.expose_secret();
+    assert!(s.chars().any(|c| c.is_ascii_uppercase()));
+    assert!(s.chars().any(|c| c.is_ascii_lowercase()));
+    assert!(s.chars().any(|c| c.is_ascii_digit()));
+    assert_ne!(
+        DynamicRng::generate_alphanumeric(32).expose_secret(),
+        DynamicRng::generate_alphanumeric(32).expose_secret()
+    );
+}
+
+// ──────────────────────────────────────────────────────────────
+// generate_distinct() — duplicate-free batches
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn generate_distinct_short_codes_have_no_duplicates() {
+    // 1-byte codes: 200 of 256 possible values forces many retries
+    let codes = FixedRng::<1>::generate_distinct::<200>();
+    let mut seen = std::collections::HashSet::new();
+    for code in &codes {
+        assert!(seen.insert(code.expose_secret()[0]));
+    }
+}
+
+#[test]
+fn generate_distinct_full_space() {
+    let codes = FixedRng::<1>::generate_distinct::<256>();
+    let mut values: Vec<u8> = codes.iter().map(|c| c.expose_secret()[0]).collect();
+    values.sort_unstable();
+    assert_eq!(values, (0..=255u8).collect::<Vec<_>>());
+}
+
+#[test]
+#[should_panic(expected = "K exceeds the number of distinct N-byte values")]
+fn generate_distinct_impossible_batch_panics() {
+    let _ = FixedRng::<1>::generate_distinct::<257>();
+}
+
+// ──────────────────────────────────────────────────────────────
+// gen_range_u64() / gen_range_usize() — bias-free bounded integers
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn gen_range_stays_in_bounds_and_covers_range() {
+    let mut seen = [false; 6];
+    for _ in 0..2_000 {
+        let v = *DynamicRng::gen_range_u64(10, 16).expose_secret();
+        assert!((10..16).contains(&v));
+        seen[(v - 10) as usize] = true;
+    }
+    assert!(seen.iter().all(|&s| s));
+
+    for _ in 0..100 {
+        assert!(*DynamicRng::gen_range_usize(0, 3).expose_secret() < 3);
+    }
+    assert_eq!(*DynamicRng::gen_range_u64(41, 42).expose_secret(), 41);
+}
+
+#[test]
+fn gen_range_full_width_and_redacted() {
+    let v = DynamicRng::gen_range_u64(0, u64::MAX);
+    assert!(*v.expose_secret() < u64::MAX);
+    assert_eq!(format!("{v:?}"), "[REDACTED]");
+}
+
+#[test]
+#[should_panic(expected = "empty range")]
+fn gen_range_empty_panics() {
+    let _ = DynamicRng::gen_range_u64(5, 5);
+}
+
+// ──────────────────────────────────────────────────────────────
+// stream() — lazy, endless generation
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn stream_yields_independent_values() {
+    let pads: Vec<Fixed<[u8; 32]>> = FixedRng::<32>::stream().take(8).collect();
+    assert_eq!(pads.len(), 8);
+    for (i, a) in pads.iter().enumerate() {
+        assert_ne!(*a.expose_secret(), [0u8; 32]);
+        for b in &pads[i + 1..] {
+            assert_ne!(a.expose_secret(), b.expose_secret());
+        }
+    }
+}
+
+#[test]
+fn stream_is_lazy_and_unbounded() {
+    let mut stream = FixedRng::<16>::stream();
+    assert_eq!(stream.size_hint().1, None);
+    assert!(stream.nth(1_000).is_some());
+}
+
+// ──────────────────────────────────────────────────────────────
+// generate_u32_array() / generate_u64_array() — word seeds
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn generate_word_arrays_are_random() {
+    let a: Fixed<[u32; 8]> = generate_u32_array::<8>();
+    let b: Fixed<[u32; 8]> = generate_u32_array::<8>();
+    assert_ne!(a.expose_secret(), b.expose_secret());
+    assert_ne!(*a.expose_secret(), [0u32; 8]);
+
+    let c: Fixed<[u64; 4]> = generate_u64_array::<4>();
+    let d: Fixed<[u64; 4]> = generate_u64_array::<4>();
+    assert_ne!(c.expose_secret(), d.expose_secret());
+    assert_eq!(format!("{c:?}"), "[REDACTED]");
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn generate_word_arrays_zeroize() {
+    let mut seed = generate_u64_array::<4>();
+    seed.zeroize_now();
+    assert_eq!(*seed.expose_secret(), [0u64; 4]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// generate_with_config() — RngConfig
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn config_default_matches_generate() {
+    let config = RngConfig::default();
+    assert_eq!(config.len, 0);
+    assert!(config.alphabet.is_none());
+    assert!(!config.reject_zero);
+    assert_eq!(config.source, RngSource::Os);
+
+    let plain = DynamicRng::generate_with_config(RngConfig::new(48));
+    assert_eq!(plain.len(), 48);
+    assert!(DynamicRng::generate_with_config(RngConfig::default()).is_empty());
+}
+
+#[test]
+fn config_alphabet_and_reject_zero() {
+    let digits = DynamicRng::generate_with_config(RngConfig::new(256).alphabet(b"0123456789"));
+    assert_eq!(digits.len(), 256);
+    assert!(digits.expose_secret().iter().all(u8::is_ascii_digit));
+
+    let nonzero = DynamicRng::generate_with_config(RngConfig::new(1024).reject_zero(true));
+    assert!(!nonzero.expose_secret().contains(&0));
+
+    let ones = DynamicRng::generate_with_config(
+        RngConfig::new(64)
+            .alphabet(&[0, 1])
+            .reject_zero(true)
+            .source(RngSource::Os),
+    );
+    assert!(ones.expose_secret().iter().all(|&b| b == 1));
+}
+
+#[test]
+#[should_panic(expected = "RngConfig alphabet must hold 1..=256 bytes")]
+fn config_empty_alphabet_panics() {
+    let _ = DynamicRng::generate_with_config(RngConfig::new(4).alphabet(&[]));
+}
+
+#[test]
+#[should_panic(expected = "reject_zero with an all-zero alphabet")]
+fn config_all_zero_alphabet_panics() {
+    let _ = DynamicRng::generate_with_config(RngConfig::new(4).alphabet(&[0]).reject_zero(true));
+}
+
+// ──────────────────────────────────────────────────────────────
+// duplicate() — explicit copies of generated values
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn duplicate_copies_generated_bytes() {
+    let seed = FixedRng::<32>::generate();
+    let mut copy: Fixed<[u8; 32]> = seed.duplicate();
+    assert_eq!(copy.expose_secret(), seed.expose_secret());
+    copy.expose_secret_mut()[0] ^= 0xFF;
+    assert_ne!(copy.expose_secret(), seed.expose_secret()); // independent
+
+    let token = DynamicRng::generate(40);
+    let dup: Dynamic<Vec<u8>> = token.duplicate();
+    assert_eq!(dup.expose_secret().as_slice(), token.expose_secret());
+    assert_eq!(format!("{dup:?}"), "[REDACTED]");
+}
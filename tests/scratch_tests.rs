@@ -0,0 +1,42 @@
+// ==========================================================================
+// tests/scratch_tests.rs
+// ==========================================================================
+// Always-wiped scratch buffers (zeroize feature)
+
+#![cfg(feature = "zeroize")]
+
+use secure_gate::{with_scratch, with_scratch_vec};
+
+#[test]
+fn scratch_starts_zeroed_and_returns_result() {
+    let sum = with_scratch::<64, _>(|buf| {
+        assert!(buf.iter().all(|&b| b == 0));
+        buf.copy_from_slice(&[1u8; 64]);
+        buf.iter().map(|&b| b as u32).sum::<u32>()
+    });
+    assert_eq!(sum, 64);
+}
+
+#[test]
+fn scratch_vec_has_requested_length() {
+    let len = with_scratch_vec(1000, |buf| {
+        assert!(buf.iter().all(|&b| b == 0));
+        buf.fill(0xff);
+        buf.len()
+    });
+    assert_eq!(len, 1000);
+}
+
+#[test]
+fn scratch_panic_propagates_through_guard() {
+    let result = std::panic::catch_unwind(|| {
+        with_scratch::<16, ()>(|buf| {
+            buf.fill(0x42);
+            panic!("boom");
+        })
+    });
+    assert!(result.is_err());
+
+    let result = std::panic::catch_unwind(|| with_scratch_vec::<()>(16, |_| panic!("boom")));
+    assert!(result.is_err());
+}
@@ -0,0 +1,41 @@
+// ==========================================================================
+// tests/secrecy_tests.rs
+// ==========================================================================
+// Interop with `secrecy::SecretBox` (`secrecy-compat` feature)
+
+#![cfg(feature = "secrecy-compat")]
+
+use secrecy::{ExposeSecret, SecretBox};
+use secure_gate::{Dynamic, Fixed};
+
+// ──────────────────────────────────────────────────────────────
+// Dynamic<T> → SecretBox<T> moves the existing allocation
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_into_secret_box_keeps_allocation() {
+    let key = Dynamic::<Vec<u8>>::new(vec![7u8; 32]);
+    let ptr = key.expose_secret().as_ptr();
+
+    let boxed: SecretBox<Vec<u8>> = key.into();
+    assert_eq!(boxed.expose_secret().as_slice(), &[7u8; 32]);
+    assert_eq!(boxed.expose_secret().as_ptr(), ptr);
+}
+
+#[test]
+fn dynamic_str_into_secret_box() {
+    let pw: Dynamic<str> = Dynamic::new_boxed("hunter2".into());
+    let boxed = SecretBox::<str>::from(pw);
+    assert_eq!(boxed.expose_secret(), "hunter2");
+}
+
+// ──────────────────────────────────────────────────────────────
+// Fixed<T> → SecretBox<T> boxes the value
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_into_secret_box() {
+    let key = Fixed::new([0x42u8; 16]);
+    let boxed: SecretBox<[u8; 16]> = key.into();
+    assert_eq!(boxed.expose_secret(), &[0x42u8; 16]);
+}
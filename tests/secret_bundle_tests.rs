@@ -0,0 +1,82 @@
+// ==========================================================================
+// tests/secret_bundle_tests.rs
+// ==========================================================================
+// Panic-safe multi-secret structs via secret_bundle! (zeroize feature)
+
+#![cfg(feature = "zeroize")]
+
+use secure_gate::{secret_bundle, Dynamic, Fixed};
+use std::cell::Cell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::rc::Rc;
+use zeroize::Zeroize;
+
+// Records that it was wiped
+struct Tracker(Rc<Cell<bool>>);
+
+impl Zeroize for Tracker {
+    fn zeroize(&mut self) {
+        self.0.set(true);
+    }
+}
+
+// Panics when wiped
+struct Bomb;
+
+impl Zeroize for Bomb {
+    fn zeroize(&mut self) {
+        panic!("wipe failed");
+    }
+}
+
+secret_bundle! {
+    /// Bundle with real wrapper types
+    pub struct Session {
+        pub key: Fixed<[u8; 32]>,
+        pub token: Dynamic<String>,
+    }
+}
+
+secret_bundle! {
+    struct Fragile {
+        first: Tracker,
+        middle: Bomb,
+        last: Tracker,
+    }
+}
+
+// ──────────────────────────────────────────────────────────────
+// Basic behaviour
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn bundle_fields_are_accessible_and_debug_redacted() {
+    let session = Session {
+        key: Fixed::new([7u8; 32]),
+        token: "t0ken".into(),
+    };
+    assert_eq!(session.key.expose_secret()[0], 7);
+    assert_eq!(session.token.expose_secret(), "t0ken");
+    assert_eq!(format!("{session:?}"), "[REDACTED]");
+}
+
+// ──────────────────────────────────────────────────────────────
+// Panic safety
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn bundle_wipes_every_field_even_if_one_wipe_panics() {
+    let first = Rc::new(Cell::new(false));
+    let last = Rc::new(Cell::new(false));
+
+    let bundle = Fragile {
+        first: Tracker(first.clone()),
+        middle: Bomb,
+        last: Tracker(last.clone()),
+    };
+
+    let result = catch_unwind(AssertUnwindSafe(move || drop(bundle)));
+    assert!(result.is_err());
+    assert!(first.get(), "field before the panic was not wiped");
+    assert!(last.get(), "field after the panic was not wiped");
+}
@@ -0,0 +1,55 @@
+// ==========================================================================
+// tests/secret_list_tests.rs
+// ==========================================================================
+// Constant-time membership over compact same-size entries
+
+#![cfg(feature = "ct-eq")]
+
+use secure_gate::SecretList;
+
+#[test]
+fn secret_list_contains_ct_finds_every_position() {
+    let entries: Vec<[u8; 8]> = (0u8..16).map(|i| [i; 8]).collect();
+    let list = SecretList::from(entries);
+
+    assert_eq!(list.len(), 16);
+    for i in 0u8..16 {
+        assert!(list.contains_ct(&[i; 8]));
+    }
+    assert!(!list.contains_ct(&[0xff; 8]));
+}
+
+#[test]
+fn secret_list_empty_contains_nothing() {
+    let list = SecretList::<32>::new();
+    assert!(list.is_empty());
+    assert!(!list.contains_ct(&[0u8; 32]));
+}
+
+#[test]
+fn secret_list_single_byte_difference() {
+    let mut list = SecretList::<4>::with_capacity(1);
+    list.push([1, 2, 3, 4]);
+
+    assert!(list.contains_ct(&[1, 2, 3, 4]));
+    assert!(!list.contains_ct(&[1, 2, 3, 5]));
+    assert!(!list.contains_ct(&[0, 2, 3, 4]));
+}
+
+#[test]
+fn secret_list_debug_is_redacted() {
+    let list = SecretList::from(vec![[0x42u8; 16]]);
+    assert_eq!(format!("{list:?}"), "[REDACTED]");
+}
+
+#[test]
+fn secret_list_push_grows_and_keeps_entries() {
+    let mut list = SecretList::<4>::new();
+    for i in 0u8..100 {
+        list.push([i; 4]);
+    }
+    assert_eq!(list.len(), 100);
+    assert!(list.contains_ct(&[0; 4]));
+    assert!(list.contains_ct(&[99; 4]));
+    assert!(!list.contains_ct(&[100; 4]));
+}
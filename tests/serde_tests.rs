@@ -0,0 +1,194 @@
+// ==========================================================================
+// tests/serde_tests.rs
+// ==========================================================================
+// Transparent serde support for all wrappers (serde feature)
+
+#![cfg(feature = "serde")]
+
+use secure_gate::{Dynamic, DynamicNoClone, Fixed, FixedNoClone};
+
+// ──────────────────────────────────────────────────────────────
+// Cloneable wrappers
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_serializes_transparently() {
+    let key = Fixed::new([1u8, 2, 3, 4]);
+    assert_eq!(serde_json::to_string(&key).unwrap(), "[1,2,3,4]");
+}
+
+#[test]
+fn fixed_roundtrips() {
+    let key: Fixed<[u8; 4]> = serde_json::from_str("[9,8,7,6]").unwrap();
+    assert_eq!(key.expose_secret(), &[9, 8, 7, 6]);
+}
+
+#[test]
+fn dynamic_serializes_transparently() {
+    let pw: Dynamic<String> = "hunter2".into();
+    assert_eq!(serde_json::to_string(&pw).unwrap(), "\"hunter2\"");
+}
+
+// ──────────────────────────────────────────────────────────────
+// No-clone wrappers
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_no_clone_serializes_transparently() {
+    let key = FixedNoClone::new([0xAAu8; 3]);
+    assert_eq!(serde_json::to_string(&key).unwrap(), "[170,170,170]");
+}
+
+#[test]
+fn fixed_no_clone_roundtrips() {
+    let key = FixedNoClone::new([5u8; 8]);
+    let json = serde_json::to_string(&key).unwrap();
+    let back: FixedNoClone<[u8; 8]> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.expose_secret(), &[5u8; 8]);
+}
+
+#[test]
+fn fixed_no_clone_rejects_wrong_length() {
+    let res: Result<FixedNoClone<[u8; 4]>, _> = serde_json::from_str("[1,2,3]");
+    assert!(res.is_err());
+}
+
+#[test]
+fn dynamic_no_clone_serializes_transparently() {
+    let token = DynamicNoClone::new(Box::new(vec![1u8, 2]));
+    assert_eq!(serde_json::to_string(&token).unwrap(), "[1,2]");
+}
+
+// ──────────────────────────────────────────────────────────────
+// HexString / RandomHex — hex text on the wire
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "conversions")]
+#[test]
+fn hex_string_roundtrips_as_text() {
+    use secure_gate::HexString;
+
+    let hex = HexString::new("DEADbeef".to_string()).unwrap();
+    let json = serde_json::to_string(&hex).unwrap();
+    assert_eq!(json, "\"deadbeef\"");
+
+    let back: HexString = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, hex);
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn hex_string_deserialize_validates() {
+    use secure_gate::HexString;
+
+    let err = serde_json::from_str::<HexString>("\"abc\"").err().unwrap();
+    assert!(err.to_string().contains("invalid hex string"));
+    assert!(serde_json::from_str::<HexString>("\"zz\"").is_err());
+    assert!(serde_json::from_str::<HexString>("[1,2]").is_err());
+}
+
+#[cfg(all(feature = "conversions", feature = "rand"))]
+#[test]
+fn random_hex_serializes_as_text() {
+    use secure_gate::rng::FixedRng;
+
+    let code = FixedRng::<8>::random_hex();
+    let json = serde_json::to_string(&code).unwrap();
+    assert_eq!(json, format!("\"{}\"", code.expose_secret()));
+}
+
+// ──────────────────────────────────────────────────────────────
+// serde_base64url — `#[serde(with = ...)]` adapter for fixed keys
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "conversions")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct B64Config {
+    #[serde(with = "secure_gate::serde_base64url")]
+    key: Fixed<[u8; 4]>,
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn serde_base64url_roundtrips() {
+    let cfg = B64Config {
+        key: Fixed::new([0xde, 0xad, 0xbe, 0xef]),
+    };
+    let json = serde_json::to_string(&cfg).unwrap();
+    assert_eq!(json, r#"{"key":"3q2-7w"}"#);
+
+    let back: B64Config = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.key.expose_secret(), &[0xde, 0xad, 0xbe, 0xef]);
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn serde_base64url_rejects_bad_input() {
+    // Wrong length: 3 bytes for a 4-byte key
+    let err = serde_json::from_str::<B64Config>(r#"{"key":"AQID"}"#)
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("length mismatch"));
+
+    // Not base64url (padding and `+` are rejected)
+    assert!(serde_json::from_str::<B64Config>(r#"{"key":"3q2+7w=="}"#).is_err());
+    // Not a string at all
+    assert!(serde_json::from_str::<B64Config>(r#"{"key":[1,2,3,4]}"#).is_err());
+}
+
+// ──────────────────────────────────────────────────────────────
+// from_bincode_limited() — bounded decode into Dynamic<Vec<u8>>
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "bincode")]
+#[test]
+fn bincode_limited_decodes_within_limit() {
+    let wire = bincode::serialize(&vec![9u8; 32]).unwrap();
+    let blob = Dynamic::<Vec<u8>>::from_bincode_limited(&wire, 32).unwrap();
+    assert_eq!(blob.expose_secret(), &[9u8; 32]);
+
+    let empty = bincode::serialize(&Vec::<u8>::new()).unwrap();
+    assert!(Dynamic::<Vec<u8>>::from_bincode_limited(&empty, 0)
+        .unwrap()
+        .is_empty());
+}
+
+#[cfg(feature = "bincode")]
+#[test]
+fn bincode_limited_rejects_oversized_and_malformed() {
+    use secure_gate::SecureGateError;
+
+    let wire = bincode::serialize(&vec![9u8; 33]).unwrap();
+    assert_eq!(
+        Dynamic::<Vec<u8>>::from_bincode_limited(&wire, 32).unwrap_err(),
+        SecureGateError::CapacityExceeded {
+            capacity: 32,
+            got: 33
+        }
+    );
+
+    // Hostile length prefix is rejected before any allocation
+    let mut hostile = u64::MAX.to_le_bytes().to_vec();
+    hostile.extend_from_slice(&[1, 2, 3]);
+    assert!(matches!(
+        Dynamic::<Vec<u8>>::from_bincode_limited(&hostile, 1024),
+        Err(SecureGateError::CapacityExceeded { capacity: 1024, .. })
+    ));
+
+    // Truncated, too short for a prefix, and trailing garbage
+    let short = &wire[..wire.len() - 1];
+    assert_eq!(
+        Dynamic::<Vec<u8>>::from_bincode_limited(short, 64).unwrap_err(),
+        SecureGateError::InvalidBincode
+    );
+    assert_eq!(
+        Dynamic::<Vec<u8>>::from_bincode_limited(&[1, 2], 64).unwrap_err(),
+        SecureGateError::InvalidBincode
+    );
+    let mut trailing = wire.clone();
+    trailing.push(0);
+    assert_eq!(
+        Dynamic::<Vec<u8>>::from_bincode_limited(&trailing, 64).unwrap_err(),
+        SecureGateError::InvalidBincode
+    );
+}
@@ -0,0 +1,114 @@
+// ==========================================================================
+// tests/tracing_tests.rs
+// ==========================================================================
+// Mutable-exposure events from the `tracing` feature
+
+#![cfg(feature = "tracing")]
+
+use secure_gate::{Dynamic, Fixed};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+type Captured = Arc<Mutex<Vec<(String, BTreeMap<String, String>)>>>;
+
+// Minimal subscriber that records each event's target and fields as strings
+struct Capture(Captured);
+
+struct Fields<'a>(&'a mut BTreeMap<String, String>);
+
+impl Visit for Fields<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{value:?}"));
+    }
+}
+
+impl Subscriber for Capture {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = BTreeMap::new();
+        event.record(&mut Fields(&mut fields));
+        let target = event.metadata().target().to_string();
+        self.0.lock().unwrap().push((target, fields));
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+fn capture(f: impl FnOnce()) -> Vec<(String, BTreeMap<String, String>)> {
+    let events = Captured::default();
+    tracing::subscriber::with_default(Capture(events.clone()), f);
+    let captured = events.lock().unwrap();
+    captured.clone()
+}
+
+// ──────────────────────────────────────────────────────────────
+// Mutable exposure is reported, reads are not
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn expose_secret_mut_emits_metadata_event() {
+    let mut key = Fixed::new([0xA5u8; 4]);
+    let events = capture(|| {
+        key.expose_secret_mut()[0] = 0x5A;
+    });
+
+    assert_eq!(events.len(), 1);
+    let (target, fields) = &events[0];
+    assert_eq!(target, "secure_gate::exposure");
+    assert_eq!(fields["wrapper"], "Fixed");
+    assert_eq!(fields["type_name"], "[u8; 4]");
+    assert_eq!(fields["handle_size"], "4");
+    assert!(fields["location"].contains("tracing_tests.rs"));
+}
+
+#[test]
+fn expose_secret_is_not_traced() {
+    let key = Fixed::new([1u8; 4]);
+    let events = capture(|| {
+        let _ = key.expose_secret();
+    });
+    assert!(events.is_empty());
+}
+
+// ──────────────────────────────────────────────────────────────
+// Secret contents never appear in the event
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn event_never_contains_secret_bytes() {
+    let mut pw: Dynamic<String> = "correct horse battery staple".into();
+    let events = capture(|| {
+        pw.expose_secret_mut().push('!');
+    });
+
+    assert_eq!(events.len(), 1);
+    let (_, fields) = &events[0];
+    assert_eq!(fields["wrapper"], "Dynamic");
+    // The handle, not the 28-byte password
+    assert_eq!(fields["handle_size"], std::mem::size_of::<String>().to_string());
+    for value in fields.values() {
+        assert!(!value.contains("horse"));
+    }
+}
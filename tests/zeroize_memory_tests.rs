@@ -0,0 +1,85 @@
+// ==========================================================================
+// tests/zeroize_memory_tests.rs
+// ==========================================================================
+// Physical wiping (zeroize feature): checks that the *same* allocation is
+// overwritten in place, not just that the API reports an empty/zero value.
+
+#![cfg(feature = "zeroize")]
+
+use core::hint::black_box;
+use secure_gate::{Dynamic, DynamicNoClone, Fixed};
+
+// Read `len` bytes starting at `ptr` after the wipe.
+//
+// SAFETY (callers): `ptr` must point into an allocation that is still live and at
+// least `len` bytes long, with every byte initialized at some point.
+unsafe fn read_raw(ptr: *const u8, len: usize) -> Vec<u8> {
+    std::slice::from_raw_parts(black_box(ptr), len).to_vec()
+}
+
+#[test]
+fn dynamic_vec_wipes_same_allocation_including_spare_capacity() {
+    // Fill all 64 bytes, then truncate: the last 16 are secret spare capacity
+    let mut data = vec![0xA5u8; 64];
+    data.truncate(48);
+    let mut secret = Dynamic::<Vec<u8>>::new(data);
+    let ptr = secret.expose_secret().as_ptr();
+    let cap = secret.expose_secret().capacity();
+    assert_eq!(cap, 64);
+
+    secret.zeroize_now();
+    black_box(&mut secret);
+
+    // Same buffer, still owned by the wrapper: nothing was reallocated or freed
+    let v = secret.expose_secret();
+    assert_eq!(v.as_ptr(), ptr);
+    assert_eq!(v.capacity(), cap);
+    // SAFETY: the allocation is still owned by `secret`, and all `cap` bytes were
+    // written before the truncation.
+    let raw = unsafe { read_raw(ptr, cap) };
+    assert!(raw.iter().all(|&b| b == 0), "old bytes survived the wipe");
+}
+
+#[test]
+fn dynamic_string_wipes_same_allocation() {
+    let mut pw: Dynamic<String> = "correct horse battery staple".into();
+    let ptr = pw.expose_secret().as_ptr();
+    let len = pw.len();
+
+    pw.zeroize_now();
+    black_box(&mut pw);
+
+    assert_eq!(pw.expose_secret().as_ptr(), ptr);
+    assert!(pw.expose_secret().capacity() >= len);
+    // SAFETY: allocation still owned by `pw`, and `len` bytes were initialized
+    let raw = unsafe { read_raw(ptr, len) };
+    assert!(raw.iter().all(|&b| b == 0));
+}
+
+#[test]
+fn dynamic_no_clone_wipes_same_allocation() {
+    let mut key = DynamicNoClone::new(Box::new(vec![0x5Au8; 32]));
+    let ptr = key.expose_secret().as_ptr();
+
+    key.zeroize_now();
+    black_box(&mut key);
+
+    assert_eq!(key.expose_secret().as_ptr(), ptr);
+    // SAFETY: allocation still owned by `key`, and 32 bytes were initialized
+    let raw = unsafe { read_raw(ptr, 32) };
+    assert!(raw.iter().all(|&b| b == 0));
+}
+
+#[test]
+fn fixed_wipes_in_place() {
+    let mut key = Fixed::new([0xC3u8; 32]);
+    let ptr = key.expose_secret().as_ptr();
+
+    key.zeroize_now();
+    black_box(&mut key);
+
+    assert_eq!(key.expose_secret().as_ptr(), ptr);
+    // SAFETY: `key` has not moved (same address), so its 32 bytes are still live
+    let raw = unsafe { read_raw(ptr, 32) };
+    assert_eq!(raw, [0u8; 32]);
+}
@@ -0,0 +1,34 @@
+// ==========================================================================
+// tests/zeroizing_tests.rs
+// ==========================================================================
+// `FixedZeroizingExt` byte-array helpers on `Zeroizing` (zeroize feature)
+
+#![cfg(feature = "zeroize")]
+
+use secure_gate::{Fixed, FixedZeroizingExt};
+use zeroize::Zeroizing;
+
+#[test]
+fn from_slice_matches_fixed() {
+    let bytes = [0xA5u8; 32];
+    let key = Zeroizing::<[u8; 32]>::from_slice(&bytes);
+    let fixed = Fixed::<[u8; 32]>::from_slice(&bytes);
+    assert_eq!(*key, *fixed.expose_secret());
+    assert_eq!(key.len(), fixed.len());
+    assert!(!key.is_empty());
+}
+
+#[test]
+fn from_array_and_empty() {
+    let key: Zeroizing<[u8; 3]> = [1, 2, 3].into();
+    assert_eq!(key.len(), 3);
+
+    let empty = Zeroizing::<[u8; 0]>::from_slice(&[]);
+    assert!(empty.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "slice length mismatch")]
+fn from_slice_rejects_wrong_length() {
+    let _ = Zeroizing::<[u8; 4]>::from_slice(&[1, 2, 3]);
+}